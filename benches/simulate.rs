@@ -0,0 +1,25 @@
+//! Times the headless update path, without rendering: `cargo bench`.
+
+use std::time::Instant;
+
+use animated_memory::game::{Difficulty, Game, SpawnStrategy};
+
+const STEPS: usize = 100_000;
+const DT: f32 = 1.0 / 60.0;
+
+fn main() {
+    let word_list: Vec<String> = ["cat", "dog", "egg", "mum", "sun", "map", "owl", "fox"]
+        .iter()
+        .map(|word| word.to_string())
+        .collect();
+    let mut game = Game::new_seeded(800.0, 600.0, Difficulty::Hard, SpawnStrategy::Ring, &word_list, 1);
+
+    let start = Instant::now();
+    let (score, live_words) = game.simulate(&[], DT, STEPS);
+    let elapsed = start.elapsed();
+
+    println!(
+        "{STEPS} steps in {elapsed:?} ({:?} per step), score: {score}, words in play: {live_words}",
+        elapsed / STEPS as u32,
+    );
+}
@@ -0,0 +1,66 @@
+use good_web_game::{audio::Source, Context, GameResult};
+
+const KEYPRESS_SOUND: &str = "/sounds/keypress.wav";
+const WORD_COMPLETE_SOUND: &str = "/sounds/word_complete.wav";
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SoundEffect {
+    Keypress,
+    WordComplete,
+}
+
+/// The game's sound effects. Any that fail to load are silently skipped when played.
+#[derive(Default)]
+pub struct Sounds {
+    keypress: Option<Source>,
+    word_complete: Option<Source>,
+}
+
+impl Sounds {
+    pub fn load(ctx: &mut Context) -> Self {
+        // Blocking loads never complete on wasm, so sounds are unavailable there for now.
+        if cfg!(target_arch = "wasm32") {
+            return Self::default();
+        }
+
+        Self {
+            keypress: load_source(ctx, KEYPRESS_SOUND),
+            word_complete: load_source(ctx, WORD_COMPLETE_SOUND),
+        }
+    }
+
+    pub fn is_loaded(&self) -> bool {
+        self.keypress.is_some() || self.word_complete.is_some()
+    }
+
+    pub fn set_volume(&mut self, ctx: &mut Context, volume: f32) -> GameResult {
+        for source in [&mut self.keypress, &mut self.word_complete].into_iter().flatten() {
+            source.set_volume(ctx, volume)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn play(&self, ctx: &mut Context, effect: SoundEffect) -> GameResult {
+        let source = match effect {
+            SoundEffect::Keypress => &self.keypress,
+            SoundEffect::WordComplete => &self.word_complete,
+        };
+
+        if let Some(source) = source {
+            source.play(ctx)?;
+        }
+
+        Ok(())
+    }
+}
+
+fn load_source(ctx: &mut Context, path: &str) -> Option<Source> {
+    match Source::new(ctx, path) {
+        Ok(source) => Some(source),
+        Err(e) => {
+            eprintln!("warning: couldn't load sound {path}: {e}");
+            None
+        },
+    }
+}
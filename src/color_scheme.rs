@@ -1,6 +1,15 @@
 use ggez::graphics::Color;
 use keyframe_derive::CanTween;
 
+#[allow(dead_code)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum Theme {
+    #[default]
+    Default,
+    Deuteranopia,
+    HighContrast,
+}
+
 #[allow(dead_code)]
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum ColorPalette {
@@ -14,13 +23,99 @@ pub enum ColorPalette {
     Fg4,
     Blue,
     Red,
+    Green,
     BrightYellow,
     Orange,
     TransparentBg,
 }
 
+const THEME_COUNT: usize = 3;
+const PALETTE_SIZE: usize = 14;
+
+/// Every palette color under every theme, worked out at compile time so that converting a
+/// `ColorPalette` into a `Color` in the draw loop is just a lookup.
+const THEME_COLORS: [[Color; PALETTE_SIZE]; THEME_COUNT] = {
+    let themes = [Theme::Default, Theme::Deuteranopia, Theme::HighContrast];
+    let palette = [
+        ColorPalette::Bg,
+        ColorPalette::Bg1,
+        ColorPalette::Bg2,
+        ColorPalette::Bg3,
+        ColorPalette::Bg4,
+        ColorPalette::Fg,
+        ColorPalette::Fg0,
+        ColorPalette::Fg4,
+        ColorPalette::Blue,
+        ColorPalette::Red,
+        ColorPalette::Green,
+        ColorPalette::BrightYellow,
+        ColorPalette::Orange,
+        ColorPalette::TransparentBg,
+    ];
+
+    let mut colors = [[Color::new(0.0, 0.0, 0.0, 0.0); PALETTE_SIZE]; THEME_COUNT];
+    let mut t = 0;
+    while t < THEME_COUNT {
+        let mut c = 0;
+        while c < PALETTE_SIZE {
+            colors[themes[t] as usize][palette[c] as usize] = palette[c].build_color(themes[t]);
+            c += 1;
+        }
+        t += 1;
+    }
+
+    colors
+};
+
 impl ColorPalette {
-    fn as_rgba(self) -> (u8, u8, u8, u8) {
+    pub const BG_RGBA: (u8, u8, u8, u8) = Self::Bg.rgba();
+    pub const BG1_RGBA: (u8, u8, u8, u8) = Self::Bg1.rgba();
+    pub const BG2_RGBA: (u8, u8, u8, u8) = Self::Bg2.rgba();
+    pub const BG3_RGBA: (u8, u8, u8, u8) = Self::Bg3.rgba();
+    pub const BG4_RGBA: (u8, u8, u8, u8) = Self::Bg4.rgba();
+    pub const FG_RGBA: (u8, u8, u8, u8) = Self::Fg.rgba();
+    pub const FG0_RGBA: (u8, u8, u8, u8) = Self::Fg0.rgba();
+    pub const FG4_RGBA: (u8, u8, u8, u8) = Self::Fg4.rgba();
+    pub const BLUE_RGBA: (u8, u8, u8, u8) = Self::Blue.rgba();
+    pub const RED_RGBA: (u8, u8, u8, u8) = Self::Red.rgba();
+    pub const GREEN_RGBA: (u8, u8, u8, u8) = Self::Green.rgba();
+    pub const BRIGHT_YELLOW_RGBA: (u8, u8, u8, u8) = Self::BrightYellow.rgba();
+    pub const ORANGE_RGBA: (u8, u8, u8, u8) = Self::Orange.rgba();
+    pub const TRANSPARENT_BG_RGBA: (u8, u8, u8, u8) = Self::TransparentBg.rgba();
+
+    pub const fn as_rgba_for(self, theme: Theme) -> (u8, u8, u8, u8) {
+        match theme {
+            Theme::Default => self.rgba(),
+
+            // swaps the red/green-leaning accents for blue/orange, which stay distinguishable
+            Theme::Deuteranopia => match self {
+                Self::Red => (230, 159, 0, 255),
+                Self::Blue => (0, 114, 178, 255),
+                Self::Green => (0, 158, 115, 255),
+                Self::Orange => (213, 94, 0, 255),
+                _ => self.rgba(),
+            },
+
+            Theme::HighContrast => match self {
+                Self::Bg => (0, 0, 0, 255),
+                Self::Bg1 => (28, 28, 28, 255),
+                Self::Bg2 => (96, 96, 96, 255),
+                Self::Bg3 => (128, 128, 128, 255),
+                Self::Bg4 => (160, 160, 160, 255),
+                Self::Fg0 | Self::Fg => (255, 255, 255, 255),
+                Self::Fg4 => (210, 210, 210, 255),
+                Self::Red => (255, 40, 40, 255),
+                Self::Blue => (64, 160, 255, 255),
+                Self::Green => (0, 230, 64, 255),
+                Self::BrightYellow => (255, 230, 0, 255),
+                Self::Orange => (255, 128, 0, 255),
+                Self::TransparentBg => (0, 0, 0, 220),
+            },
+        }
+    }
+
+    /// The color under the default theme.
+    pub const fn rgba(self) -> (u8, u8, u8, u8) {
         match self {
             Self::Bg => (40, 40, 40, 255),
             Self::Bg1 => (60, 56, 54, 255),
@@ -31,27 +126,28 @@ impl ColorPalette {
             Self::Fg => (235, 219, 178, 255),
             Self::Fg4 => (168, 153, 132, 255),
             Self::Red => (204, 36, 29, 255),
+            Self::Green => (152, 151, 26, 255),
             Self::Blue => (69, 133, 136, 255),
             Self::BrightYellow => (250, 189, 47, 255),
             Self::Orange => (214, 93, 14, 255),
             Self::TransparentBg => (29, 32, 33, 200),
         }
     }
-}
 
-impl Into<Color> for ColorPalette {
-    fn into(self) -> Color {
-        let (r, g, b, a) = self.as_rgba();
-        Color::from_rgba(r, g, b, a)
+    /// The drawable color under `theme`.
+    pub fn color_for(self, theme: Theme) -> Color {
+        THEME_COLORS[theme as usize][self as usize]
     }
-}
 
-impl Into<TweenableColor> for ColorPalette {
-    fn into(self) -> TweenableColor {
-        let (r, g, b, a) = self.as_rgba();
-        
-        let color = Color::from_rgba(r, g, b, a);
+    const fn build_color(self, theme: Theme) -> Color {
+        let (r, g, b, a) = self.as_rgba_for(theme);
+
+        Color::new(r as f32 / 255.0, g as f32 / 255.0, b as f32 / 255.0, a as f32 / 255.0)
+    }
+}
 
+impl From<Color> for TweenableColor {
+    fn from(color: Color) -> Self {
         TweenableColor {
             r: color.r,
             g: color.g,
@@ -75,3 +171,76 @@ impl From<TweenableColor> for Color {
         Color::new(tc.r, tc.g, tc.b, tc.a)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const ALL_COLORS: [ColorPalette; 14] = [
+        ColorPalette::Bg,
+        ColorPalette::Bg1,
+        ColorPalette::Bg2,
+        ColorPalette::Bg3,
+        ColorPalette::Bg4,
+        ColorPalette::Fg,
+        ColorPalette::Fg0,
+        ColorPalette::Fg4,
+        ColorPalette::Blue,
+        ColorPalette::Red,
+        ColorPalette::Green,
+        ColorPalette::BrightYellow,
+        ColorPalette::Orange,
+        ColorPalette::TransparentBg,
+    ];
+
+    #[test]
+    fn test_all_colors_have_rgba() {
+        for color in ALL_COLORS {
+            for theme in [Theme::Default, Theme::Deuteranopia, Theme::HighContrast] {
+                let (_, _, _, a) = color.as_rgba_for(theme);
+
+                assert!(a > 0, "{color:?} is invisible in {theme:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_color_for() {
+        assert_eq!(ColorPalette::Fg.color_for(Theme::Default).to_rgba(), ColorPalette::FG_RGBA);
+        assert_eq!(ColorPalette::Red.color_for(Theme::Deuteranopia).to_rgba(), ColorPalette::Red.as_rgba_for(Theme::Deuteranopia));
+        assert_eq!(
+            ColorPalette::TransparentBg.color_for(Theme::HighContrast).to_rgba(),
+            ColorPalette::TransparentBg.as_rgba_for(Theme::HighContrast),
+        );
+    }
+
+    #[test]
+    fn test_const_colors_match_methods() {
+        const FG: (u8, u8, u8, u8) = ColorPalette::Fg.rgba();
+
+        assert_eq!(FG, ColorPalette::Fg.as_rgba_for(Theme::Default));
+        assert_eq!(ColorPalette::BG_RGBA, ColorPalette::Bg.rgba());
+        assert_eq!(ColorPalette::TRANSPARENT_BG_RGBA, ColorPalette::TransparentBg.rgba());
+
+        for theme in [Theme::Default, Theme::Deuteranopia, Theme::HighContrast] {
+            for color in ALL_COLORS {
+                let (r, g, b, a) = color.as_rgba_for(theme);
+
+                assert_eq!(THEME_COLORS[theme as usize][color as usize], Color::from_rgba(r, g, b, a), "{color:?} in {theme:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_themes_are_distinct() {
+        let themes = [Theme::Default, Theme::Deuteranopia, Theme::HighContrast];
+
+        for color in [ColorPalette::Red, ColorPalette::Blue] {
+            for (i, a) in themes.iter().enumerate() {
+                for b in &themes[i + 1..] {
+                    assert_ne!(color.as_rgba_for(*a), color.as_rgba_for(*b), "{color:?}: {a:?} vs {b:?}");
+                }
+            }
+        }
+    }
+}
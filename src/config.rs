@@ -0,0 +1,164 @@
+use std::fs;
+
+use good_web_game::conf::Conf;
+
+use crate::{
+    game::Difficulty,
+    menu::MAIN_MENU_TITLE,
+    resource_dir,
+    screen::{SCREEN_HEIGHT, SCREEN_WIDTH},
+};
+
+const CONFIG_FILE: &str = "config.toml";
+
+/// Startup options, read from `config.toml` in the resources directory.
+///
+/// Only a flat subset of TOML is understood: `key = value` lines, where values are integers
+/// or double-quoted strings, plus blank lines and `#` comments.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Config {
+    pub width: i32,
+    pub height: i32,
+    pub title: String,
+    /// Overrides the saved difficulty setting, if given.
+    pub difficulty: Option<Difficulty>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            width: SCREEN_WIDTH,
+            height: SCREEN_HEIGHT,
+            title: MAIN_MENU_TITLE.to_string(),
+            difficulty: None,
+        }
+    }
+}
+
+impl Config {
+    pub fn conf(&self) -> Conf {
+        Conf::default()
+            .window_width(self.width)
+            .window_height(self.height)
+            .window_title(self.title.clone())
+    }
+}
+
+/// Reads the config file, falling back to the defaults if it is missing or malformed.
+pub fn load() -> Config {
+    let path = resource_dir().join(CONFIG_FILE);
+
+    match fs::read_to_string(path) {
+        Ok(contents) => from_toml(&contents),
+        Err(_) => Config::default(),
+    }
+}
+
+fn from_toml(contents: &str) -> Config {
+    parse_config(contents).unwrap_or_else(|e| {
+        eprintln!("warning: ignoring {CONFIG_FILE}: {e}");
+        Config::default()
+    })
+}
+
+fn parse_config(contents: &str) -> Result<Config, String> {
+    let mut config = Config::default();
+
+    for (i, line) in contents.lines().enumerate() {
+        let line = line.trim();
+
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let (key, value) = line.split_once('=')
+            .ok_or_else(|| format!("line {}: expected `key = value`", i + 1))?;
+
+        let key = key.trim();
+        let value = value.trim();
+
+        match key {
+            "width" => config.width = parse_dimension(value).ok_or_else(|| format!("line {}: bad width", i + 1))?,
+            "height" => config.height = parse_dimension(value).ok_or_else(|| format!("line {}: bad height", i + 1))?,
+            "title" => config.title = parse_string(value).ok_or_else(|| format!("line {}: bad title", i + 1))?,
+            "difficulty" => {
+                config.difficulty = parse_string(value)
+                    .and_then(|name| parse_difficulty(&name))
+                    .map(Some)
+                    .ok_or_else(|| format!("line {}: bad difficulty", i + 1))?
+            },
+            _ => return Err(format!("line {}: unknown key `{key}`", i + 1)),
+        }
+    }
+
+    Ok(config)
+}
+
+fn strip_comment(value: &str) -> &str {
+    value.split('#').next().unwrap_or(value).trim()
+}
+
+fn parse_dimension(value: &str) -> Option<i32> {
+    strip_comment(value).parse().ok().filter(|dimension| *dimension > 0)
+}
+
+fn parse_string(value: &str) -> Option<String> {
+    let rest = value.strip_prefix('"')?;
+    let (string, after) = rest.split_once('"')?;
+
+    if !strip_comment(after).is_empty() {
+        return None;
+    }
+
+    Some(string.to_string())
+}
+
+fn parse_difficulty(name: &str) -> Option<Difficulty> {
+    match name.to_lowercase().as_str() {
+        "easy" => Some(Difficulty::Easy),
+        "normal" => Some(Difficulty::Normal),
+        "hard" => Some(Difficulty::Hard),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_config() {
+        let contents = r#"
+            # window
+            width = 1024
+            height = 768  # taller
+            title = "Typing Practice"
+
+            difficulty = "Hard"
+        "#;
+
+        assert_eq!(parse_config(contents), Ok(Config {
+            width: 1024,
+            height: 768,
+            title: "Typing Practice".to_string(),
+            difficulty: Some(Difficulty::Hard),
+        }));
+    }
+
+    #[test]
+    fn test_parse_config_partial() {
+        let config = parse_config("width = 640").unwrap();
+
+        assert_eq!(config.width, 640);
+        assert_eq!(config.height, SCREEN_HEIGHT);
+        assert_eq!(config.difficulty, None);
+    }
+
+    #[test]
+    fn test_garbage_config_uses_defaults() {
+        assert_eq!(from_toml("not a config file"), Config::default());
+        assert_eq!(from_toml("width = wide"), Config::default());
+        assert_eq!(from_toml("difficulty = \"impossible\""), Config::default());
+        assert_eq!(from_toml("colour = 3"), Config::default());
+    }
+}
@@ -0,0 +1,69 @@
+use ggez::timer;
+
+/// Seed used when the clock can't be read, so the challenge still works, just without rotating.
+const FALLBACK_SEED: u64 = 20240101;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Date {
+    pub year: i64,
+    pub month: u32,
+    pub day: u32,
+}
+
+impl Date {
+    /// The UTC calendar date `secs` seconds after the Unix epoch.
+    pub fn from_unix_secs(secs: f64) -> Self {
+        // Howard Hinnant's days-to-civil algorithm
+        let z = (secs / 86_400.0).floor() as i64 + 719_468;
+        let era = z.div_euclid(146_097);
+        let doe = z.rem_euclid(146_097);
+        let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+        let year = yoe + era * 400 + i64::from(month <= 2);
+
+        Self { year, month, day }
+    }
+}
+
+/// The seed everyone playing on `date` shares, e.g. `20240315`.
+pub fn daily_seed(date: Date) -> u64 {
+    (date.year.max(0) as u64) * 10_000 + date.month as u64 * 100 + date.day as u64
+}
+
+/// Today's seed, or `FALLBACK_SEED` if the platform clock isn't available.
+pub fn today_seed() -> u64 {
+    // goes through miniquad, which asks the browser for the date on WASM
+    let now = timer::time();
+
+    if now.is_finite() && now > 0.0 {
+        daily_seed(Date::from_unix_secs(now))
+    } else {
+        eprintln!("warning: couldn't read the date, using the fallback daily seed");
+        FALLBACK_SEED
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_from_unix_secs() {
+        assert_eq!(Date::from_unix_secs(0.0), Date { year: 1970, month: 1, day: 1 });
+        assert_eq!(Date::from_unix_secs(951_782_400.0), Date { year: 2000, month: 2, day: 29 });
+        assert_eq!(Date::from_unix_secs(1_710_460_800.0 + 3_600.0), Date { year: 2024, month: 3, day: 15 });
+    }
+
+    #[test]
+    fn test_daily_seed() {
+        let date = Date { year: 2024, month: 3, day: 15 };
+
+        assert_eq!(daily_seed(date), 20240315);
+        assert_eq!(daily_seed(date), daily_seed(Date { ..date }));
+        assert_ne!(daily_seed(date), daily_seed(Date { day: 16, ..date }));
+        assert_ne!(daily_seed(date), daily_seed(Date { year: 2025, ..date }));
+    }
+}
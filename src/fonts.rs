@@ -0,0 +1,49 @@
+use good_web_game::{graphics::Font, Context, GameResult};
+
+use crate::screen::SCREEN_HEIGHT;
+
+/// Optional font in the resources directory that replaces the built-in one.
+const FONT_FILE: &str = "/font.ttf";
+
+/// Smallest fraction of its base size text shrinks to, so it stays legible in tiny windows.
+const MIN_TEXT_SCALE: f32 = 0.5;
+
+/// Loads `font.ttf` from the resources directory, or the default font if it can't be read.
+pub fn load_font(ctx: &mut Context) -> Font {
+    font_or_default(Font::new(ctx, FONT_FILE))
+}
+
+/// Text scale `base`, designed for the default window height, adjusted to `screen_height`.
+pub fn scaled(base: f32, screen_height: f32) -> f32 {
+    base * (screen_height / SCREEN_HEIGHT as f32).max(MIN_TEXT_SCALE)
+}
+
+fn font_or_default(loaded: GameResult<Font>) -> Font {
+    loaded.unwrap_or_else(|e| {
+        eprintln!("warning: couldn't load {FONT_FILE}, using the default font: {e}");
+        Font::default()
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use good_web_game::GameError;
+
+    #[test]
+    fn test_scaled() {
+        assert_eq!(scaled(24.0, 600.0), 24.0);
+        assert_eq!(scaled(24.0, 1200.0), 48.0);
+        assert_eq!(scaled(48.0, 450.0), 36.0);
+        assert_eq!(scaled(48.0, 100.0), 24.0);
+        assert_eq!(scaled(48.0, 0.0), 24.0);
+    }
+
+    #[test]
+    fn test_missing_font_falls_back_to_default() {
+        let missing = Err(GameError::ResourceNotFound(FONT_FILE.to_string(), vec![]));
+
+        assert_eq!(font_or_default(missing), Font::default());
+    }
+}
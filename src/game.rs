@@ -1,88 +1,831 @@
 use std::{
-    collections::HashSet, 
+    collections::{HashSet, VecDeque}, 
     f32::{self, consts::PI},
+    ops::RangeInclusive,
 };
 
 use good_web_game::{
     Context,
     event::{
         self,
-        EventHandler,
+        MouseButton,
     }, 
     GameResult, 
     GameError,
     graphics::{
         self,
+        Color,
         DrawMode,
+        Font,
+        Mesh,
         Point2,
+        Rect,
         Text, 
         TextFragment,
         Vector2,
     },
     input::keyboard::{KeyCode, pressed_keys}, 
 };
-use keyframe::{functions::{EaseInOut, Linear}, AnimationSequence, Keyframe };
-use rand::{prelude::SliceRandom, Rng, thread_rng};
+use rand::{prelude::SliceRandom, rngs::StdRng, Rng, SeedableRng, thread_rng};
 
 use crate::{
-    menu::{MainMenu, Menu, EXIT, MAIN_MENU, NEW_GAME, PAUSE_MENU_TITLE, RESUME}, 
+    audio::{SoundEffect, Sounds},
+    menu::{
+        MainMenu, Menu, MenuAction, SettingsMenu, ANIMALS, CASE_SENSITIVE, COLORS, CUSTOM, DIFFICULTY, DIFFICULTY_MENU_TITLE, EASY,
+        EXIT_CONFIRM_MENU_ITEMS, EXIT_CONFIRM_MENU_TITLE, HELP_TITLE, LEADERBOARD_TITLE,
+        GAME_OVER_MENU_TITLE, GAME_SPEED, HARD, HIGH_VISIBILITY, KEY_HINTS, LAYOUT, LAYOUT_DVORAK, LAYOUT_QWERTY, MAIN_MENU, NEW_GAME, NORMAL,
+        ON, PAUSE_MENU_ITEMS, PAUSE_MENU_TITLE, PLAYER_BLUE, PLAYER_COLOR, PLAYER_GREEN, PLAYER_ORANGE, PLAYER_YELLOW, PROGRAMMING, SHADE_ALPHA, SPAWN_EDGES, SPAWN_PATTERN, SPAWN_RING, SPEED_NORMAL, SPEED_SLOW,
+        SPEED_SLOWER,
+        THEME, THEME_DEFAULT, THEME_DEUTERANOPIA, THEME_HIGH_CONTRAST, THREE_LETTER, TRAJECTORIES, VOLUME,
+        VOLUME_100, VOLUME_25, VOLUME_50, VOLUME_75, VOLUME_OFF, WORD_PACK_MENU_TITLE,
+    }, 
+    color_scheme::Theme,
+    daily::today_seed,
+    fonts::scaled,
+    keymap::Keymap,
+    leaderboard::Leaderboard,
+    particle::{burst, Particle},
+    rolling_average::RollingAverage,
+    settings::Settings,
+    score::{load_high_score, save_high_score},
+    stats::Stats,
     ColorPalette, 
-    word::{Word, WordState},
+    ui::TextInput,
+    word::{DeathPalette, Word, WordEvent, WordState},
+    words::{builtin_word_list, load_word_list, WordPack},
 };
 
-
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum GameState {
     Active,
     MainMenu,
+    DifficultySelect,
+    WordPackSelect,
+    Settings,
+    /// The controls, explained.
+    Help,
+    /// The best scores so far.
+    HighScores,
     Paused,
+    /// Asking whether to really exit, over the paused game.
+    ConfirmExit,
+    /// Typing a name for the leaderboard, after a score good enough to be on it.
+    EnterName,
+    GameOver,
 }
 
 use GameState::*;
 
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Difficulty {
+    Easy,
+    Normal,
+    Hard,
+}
+
+impl Difficulty {
+    fn from_menu_item(item: &str) -> Option<Self> {
+        match item {
+            EASY => Some(Self::Easy),
+            NORMAL => Some(Self::Normal),
+            HARD => Some(Self::Hard),
+            _ => None,
+        }
+    }
+
+    fn menu_item(self) -> &'static str {
+        match self {
+            Self::Easy => EASY,
+            Self::Normal => NORMAL,
+            Self::Hard => HARD,
+        }
+    }
+
+    /// Multiplier applied to how quickly words drift toward the player.
+    fn speed(self) -> f32 {
+        match self {
+            Self::Easy => 0.65,
+            Self::Normal => 1.0,
+            Self::Hard => 1.5,
+        }
+    }
+
+    /// How many letters the words played at this difficulty may have.
+    pub fn word_length_range(self) -> RangeInclusive<usize> {
+        match self {
+            Self::Easy => 3..=3,
+            Self::Normal => 1..=8,
+            Self::Hard => 1..=usize::MAX,
+        }
+    }
+
+    /// Seconds between new words appearing.
+    pub fn spawn_interval(self) -> f64 {
+        match self {
+            Self::Easy => 2.5,
+            Self::Normal => 1.5,
+            Self::Hard => 0.9,
+        }
+    }
+}
+
+fn theme_from_menu_item(item: &str) -> Option<Theme> {
+    match item {
+        THEME_DEFAULT => Some(Theme::Default),
+        THEME_DEUTERANOPIA => Some(Theme::Deuteranopia),
+        THEME_HIGH_CONTRAST => Some(Theme::HighContrast),
+        _ => None,
+    }
+}
+
+fn keymap_from_menu_item(item: &str) -> Option<Keymap> {
+    match item {
+        LAYOUT_QWERTY => Some(Keymap::qwerty()),
+        LAYOUT_DVORAK => Some(Keymap::dvorak()),
+        _ => None,
+    }
+}
+
+fn volume_from_menu_item(item: &str) -> Option<f32> {
+    match item {
+        VOLUME_OFF => Some(0.0),
+        VOLUME_25 => Some(0.25),
+        VOLUME_50 => Some(0.5),
+        VOLUME_75 => Some(0.75),
+        VOLUME_100 => Some(1.0),
+        _ => None,
+    }
+}
+
+fn player_color_from_menu_item(item: &str) -> Option<ColorPalette> {
+    match item {
+        PLAYER_ORANGE => Some(ColorPalette::Orange),
+        PLAYER_YELLOW => Some(ColorPalette::BrightYellow),
+        PLAYER_GREEN => Some(ColorPalette::Green),
+        PLAYER_BLUE => Some(ColorPalette::Blue),
+        _ => None,
+    }
+}
+
+fn spawn_strategy_from_menu_item(item: &str) -> Option<SpawnStrategy> {
+    match item {
+        SPAWN_RING => Some(SpawnStrategy::Ring),
+        SPAWN_EDGES => Some(SpawnStrategy::Edges),
+        _ => None,
+    }
+}
+
+fn time_scale_from_menu_item(item: &str) -> Option<f32> {
+    match item {
+        SPEED_NORMAL => Some(1.0),
+        SPEED_SLOW => Some(0.75),
+        SPEED_SLOWER => Some(0.5),
+        _ => None,
+    }
+}
+
+/// What choosing Exit does.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum ExitBehavior {
+    Quit,
+    ReturnToMenu,
+}
+
+/// In a browser the page can't be closed from inside, and quitting would only freeze the game.
+fn exit_behavior(wasm: bool) -> ExitBehavior {
+    if wasm {
+        ExitBehavior::ReturnToMenu
+    } else {
+        ExitBehavior::Quit
+    }
+}
+
+const HELP_TEXT: &str = "\
+Type a word's letters to destroy it before it reaches you
+Left and Right arrows move you out of the way
+Escape pauses the game
+Arrow keys and Enter, or the mouse, work the menus
+F11 toggles fullscreen";
+
+/// The state answering the exit confirmation leads to, or `None` if the player confirmed.
+fn exit_confirmation_state(action: Option<MenuAction>) -> Option<GameState> {
+    match action {
+        Some(MenuAction::ConfirmExit) => None,
+        _ => Some(Paused),
+    }
+}
+
+/// The state `keycode` leads to when it opens or closes the help screen, given the main menu's
+/// selected `action`, or `None` if it does neither.
+fn help_routing(state: GameState, keycode: KeyCode, action: Option<MenuAction>) -> Option<GameState> {
+    match (state, keycode) {
+        (MainMenu, KeyCode::Enter) if action == Some(MenuAction::Help) => Some(Help),
+        (Help, KeyCode::Enter | KeyCode::Escape) => Some(MainMenu),
+        _ => None,
+    }
+}
+
+/// Whether `word` has anything to type under `keymap`; one that doesn't could never be finished.
+fn is_typeable(word: &str, keymap: &Keymap) -> bool {
+    word.chars().any(|ch| keymap.key_for(ch).is_some())
+}
+
+/// Whether any of the words can be typed under `keymap`, so that a game over them can be played.
+fn has_typeable_words(word_list: &[String], keymap: &Keymap) -> bool {
+    word_list.iter().any(|word| is_typeable(word, keymap))
+}
+
+/// The words that can be typed under `keymap`, without duplicates: those would advance together on
+/// every keystroke.
+fn typeable_words(word_list: &[String], keymap: &Keymap) -> Vec<String> {
+    let mut seen = HashSet::new();
+
+    word_list.iter()
+        .filter(|word| is_typeable(word, keymap))
+        .filter(|word| seen.insert(word.as_str()))
+        .cloned()
+        .collect()
+}
+
+/// The words with lengths in `range`, or all of them if none are, e.g. for a pack of long words.
+fn filter_word_length(word_list: Vec<String>, range: RangeInclusive<usize>) -> Vec<String> {
+    let in_range: Vec<String> = word_list.iter()
+        .filter(|word| range.contains(&word.chars().count()))
+        .cloned()
+        .collect();
+
+    if in_range.is_empty() {
+        word_list
+    } else {
+        in_range
+    }
+}
+
+/// A frame longer than this means the window wasn't being drawn, most likely for losing focus.
+const FOCUS_LOST_FRAME_SECS: f32 = 0.5;
+
+/// The state to be in after the window loses focus: a running game pauses, anything else stays put.
+fn on_focus_lost(state: GameState) -> GameState {
+    match state {
+        Active => Paused,
+        state => state,
+    }
+}
+
+/// The most characters a leaderboard name can have.
+const NAME_MAX_LEN: usize = 12;
+/// The leaderboard name used when none is typed.
+const DEFAULT_PLAYER_NAME: &str = "Anonymous";
+
+/// Number of frames the debug overlay's FPS is averaged over.
+const FPS_SAMPLES: usize = 30;
+
 pub struct GameManager<'a> {
     game_state: GameState,
     game: Game,
     main_menu: MainMenu<'a>,
-    pause_menu: Menu<'a>
+    difficulty_menu: Menu<'a>,
+    word_pack_menu: Menu<'a>,
+    settings_menu: SettingsMenu<'a>,
+    pause_menu: Menu<'a>,
+    exit_confirm_menu: Menu<'a>,
+    game_over_menu: Menu<'a>,
+    high_score: usize,
+    leaderboard: Leaderboard,
+    /// The name being typed for the leaderboard.
+    name_input: TextInput,
+    difficulty: Difficulty,
+    word_pack: WordPack,
+    volume: f32,
+    theme: Theme,
+    keymap: Keymap,
+    time_scale: f32,
+    show_trajectories: bool,
+    show_key_hints: bool,
+    case_sensitive: bool,
+    high_visibility: bool,
+    player_color: ColorPalette,
+    spawn_strategy: SpawnStrategy,
+    font: Font,
+    fullscreen: bool,
+    /// Set when a game starts or resumes, so that frame's length isn't taken for a lost focus.
+    first_frame: bool,
+    /// Development info drawn in the corner, toggled with F3.
+    debug_overlay: bool,
+    fps: RollingAverage,
+}
+
+impl<'a> Default for GameManager<'a> {
+    fn default() -> Self {
+        Self::new(Settings::default())
+    }
 }
 
 impl<'a> GameManager<'a> {
-    pub fn new() -> Self {
-        Self {
+    /// A manager starting at the main menu, with the settings menu set to `settings`.
+    pub fn new(settings: Settings) -> Self {
+        let high_score = load_high_score();
+
+        let mut main_menu = MainMenu::new();
+        main_menu.set_high_score(high_score);
+
+        let mut game_manager = Self {
             game_state: MainMenu,
-            game: Game::new(0.0, 0.0),
-            main_menu: MainMenu::new(),
-            pause_menu: Menu::new(PAUSE_MENU_TITLE, &[RESUME, MAIN_MENU, EXIT]).shade_background(true),
+            game: Game::new(0.0, 0.0, Difficulty::Normal, SpawnStrategy::default(), &[]),
+            main_menu,
+            difficulty_menu: Menu::new(DIFFICULTY_MENU_TITLE, &[
+                (EASY, MenuAction::SelectDifficulty(Difficulty::Easy)),
+                (NORMAL, MenuAction::SelectDifficulty(Difficulty::Normal)),
+                (HARD, MenuAction::SelectDifficulty(Difficulty::Hard)),
+            ]).with_descriptions(&[
+                Some("Fewer, slower words"),
+                Some("The standard pace"),
+                Some("Words come thick and fast"),
+            ]),
+            word_pack_menu: Menu::new(WORD_PACK_MENU_TITLE, &[
+                (THREE_LETTER, MenuAction::SelectWordPack(WordPack::ThreeLetter)),
+                (ANIMALS, MenuAction::SelectWordPack(WordPack::Animals)),
+                (COLORS, MenuAction::SelectWordPack(WordPack::Colors)),
+                (PROGRAMMING, MenuAction::SelectWordPack(WordPack::Programming)),
+                (CUSTOM, MenuAction::SelectWordPack(WordPack::Custom)),
+            ]).with_descriptions(&[
+                Some("Short words, good for warming up"),
+                Some("Creatures great and small"),
+                Some("Every shade of the rainbow"),
+                Some("Keywords and jargon from code"),
+                Some("Your own list from words.txt"),
+            ]),
+            settings_menu: SettingsMenu::new(),
+            pause_menu: Menu::new(PAUSE_MENU_TITLE, &PAUSE_MENU_ITEMS).shade_background(SHADE_ALPHA),
+            exit_confirm_menu: Menu::new(EXIT_CONFIRM_MENU_TITLE, &EXIT_CONFIRM_MENU_ITEMS).shade_background(SHADE_ALPHA),
+            game_over_menu: Menu::new(GAME_OVER_MENU_TITLE, &[
+                (NEW_GAME, MenuAction::NewGame),
+                (MAIN_MENU, MenuAction::MainMenu),
+            ]).shade_background(SHADE_ALPHA),
+            high_score,
+            leaderboard: Leaderboard::load(),
+            name_input: TextInput::new(NAME_MAX_LEN),
+            difficulty: Difficulty::Normal,
+            word_pack: WordPack::default(),
+            volume: 1.0,
+            theme: Theme::default(),
+            keymap: Keymap::default(),
+            time_scale: 1.0,
+            show_trajectories: false,
+            show_key_hints: false,
+            case_sensitive: false,
+            high_visibility: false,
+            player_color: ColorPalette::Orange,
+            spawn_strategy: SpawnStrategy::default(),
+            font: Font::default(),
+            fullscreen: false,
+            first_frame: false,
+            debug_overlay: false,
+            fps: RollingAverage::new(FPS_SAMPLES),
+        };
+
+        for (label, value) in settings.values() {
+            game_manager.settings_menu.set_value(label, value);
+        }
+        game_manager.apply_settings();
+
+        game_manager
+    }
+
+    /// Sets the difficulty new games start at.
+    pub fn with_difficulty(mut self, difficulty: Difficulty) -> Self {
+        self.difficulty = difficulty;
+        self.settings_menu.set_value(DIFFICULTY, difficulty.menu_item());
+
+        self
+    }
+
+    /// Sets the font words and menus are drawn in.
+    pub fn with_font(mut self, font: Font) -> Self {
+        self.font = font;
+        self.main_menu.set_font(font);
+        self.difficulty_menu.set_font(font);
+        self.word_pack_menu.set_font(font);
+        self.settings_menu.set_font(font);
+        self.pause_menu.set_font(font);
+        self.exit_confirm_menu.set_font(font);
+        self.game_over_menu.set_font(font);
+
+        self
+    }
+
+    pub fn set_theme(&mut self, theme: Theme) {
+        self.theme = theme;
+        self.main_menu.set_theme(theme);
+        self.difficulty_menu.set_theme(theme);
+        self.word_pack_menu.set_theme(theme);
+        self.settings_menu.set_theme(theme);
+        self.pause_menu.set_theme(theme);
+        self.exit_confirm_menu.set_theme(theme);
+        self.game_over_menu.set_theme(theme);
+        self.game.set_theme(theme);
+    }
+
+    /// Copies the values chosen in the settings menu onto the manager.
+    fn apply_settings(&mut self) {
+        if let Some(difficulty) = self.settings_menu.value(DIFFICULTY).and_then(Difficulty::from_menu_item) {
+            self.difficulty = difficulty;
+        }
+
+        if let Some(volume) = self.settings_menu.value(VOLUME).and_then(volume_from_menu_item) {
+            self.volume = volume;
+        }
+
+        if let Some(theme) = self.settings_menu.value(THEME).and_then(theme_from_menu_item) {
+            if theme != self.theme {
+                self.set_theme(theme);
+            }
+        }
+
+        if let Some(keymap) = self.settings_menu.value(LAYOUT).and_then(keymap_from_menu_item) {
+            self.keymap = keymap;
+        }
+
+        if let Some(time_scale) = self.settings_menu.value(GAME_SPEED).and_then(time_scale_from_menu_item) {
+            self.time_scale = time_scale;
+        }
+
+        if let Some(player_color) = self.settings_menu.value(PLAYER_COLOR).and_then(player_color_from_menu_item) {
+            self.player_color = player_color;
+        }
+
+        if let Some(spawn_strategy) = self.settings_menu.value(SPAWN_PATTERN).and_then(spawn_strategy_from_menu_item) {
+            self.spawn_strategy = spawn_strategy;
+        }
+
+        self.show_trajectories = self.settings_menu.value(TRAJECTORIES) == Some(ON);
+        self.show_key_hints = self.settings_menu.value(KEY_HINTS) == Some(ON);
+        self.case_sensitive = self.settings_menu.value(CASE_SENSITIVE) == Some(ON);
+        self.high_visibility = self.settings_menu.value(HIGH_VISIBILITY) == Some(ON);
+    }
+
+    /// Quits, or where quitting isn't possible, abandons the game and heads back to the main menu.
+    fn request_exit(&mut self, ctx: &mut Context) {
+        match exit_behavior(cfg!(target_arch = "wasm32")) {
+            ExitBehavior::Quit => event::quit(ctx),
+            ExitBehavior::ReturnToMenu => {
+                self.main_menu.show_resume(false);
+                self.game_state = MainMenu;
+            },
+        }
+    }
+
+    /// Asks before exiting, starting on No so a stray Enter doesn't lose the game.
+    fn confirm_exit(&mut self) {
+        self.exit_confirm_menu.select_action(MenuAction::CancelExit);
+        self.game_state = ConfirmExit;
+    }
+
+    /// Flips the fullscreen flag, returning the new mode, unless the keypress is a held-down repeat.
+    fn toggle_fullscreen(&mut self, repeat: bool) -> Option<bool> {
+        if repeat {
+            return None;
+        }
+
+        self.fullscreen = !self.fullscreen;
+
+        Some(self.fullscreen)
+    }
+
+    fn select_difficulty(&mut self) {
+        self.difficulty_menu.select_action(MenuAction::SelectDifficulty(self.difficulty));
+        self.game_state = DifficultySelect;
+    }
+
+    fn select_word_pack(&mut self) {
+        self.word_pack_menu.select_action(MenuAction::SelectWordPack(self.word_pack));
+        self.game_state = WordPackSelect;
+    }
+
+    fn new_game(&mut self, ctx: &mut Context, gctx: &mut event::GraphicsContext, difficulty: Difficulty) {
+        let (screen_width, screen_height) = graphics::drawable_size(gctx);
+        let word_list = load_word_list(ctx, self.word_pack);
+
+        let game = Game::new(screen_width, screen_height, difficulty, self.spawn_strategy, &word_list)
+            .with_death_palette(self.word_pack.death_palette());
+
+        self.start_game(ctx, game);
+    }
+
+    /// Starts a Normal game over the built-in words, laid out by `seed` so that everyone playing
+    /// with the same seed faces the same words. Words always come in from the ring, whatever the
+    /// player's spawn setting, since that's part of the layout too.
+    fn daily_challenge(&mut self, ctx: &mut Context, gctx: &mut event::GraphicsContext, seed: u64) {
+        let (screen_width, screen_height) = graphics::drawable_size(gctx);
+        let word_list = builtin_word_list();
+
+        self.start_game(
+            ctx,
+            Game::new_seeded(screen_width, screen_height, Difficulty::Normal, SpawnStrategy::Ring, &word_list, seed),
+        );
+    }
+
+    /// Restarts the current game with the same settings, and the same layout if it was seeded.
+    fn restart(&mut self, ctx: &mut Context, gctx: &mut event::GraphicsContext) {
+        match self.game.seed() {
+            Some(seed) => self.daily_challenge(ctx, gctx, seed),
+            None => self.new_game(ctx, gctx, self.game.difficulty()),
+        }
+    }
+
+    fn start_game(&mut self, ctx: &mut Context, game: Game) {
+        // reuse the previous game's sounds rather than loading them again
+        let mut sounds = std::mem::take(&mut self.game.sounds);
+        if !sounds.is_loaded() {
+            sounds = Sounds::load(ctx);
+        }
+
+        if let Err(e) = sounds.set_volume(ctx, self.volume) {
+            eprintln!("warning: couldn't set volume: {e}");
+        }
+
+        self.game = game
+            .with_keymap(self.keymap.clone())
+            .with_time_scale(self.time_scale)
+            .with_trajectories(self.show_trajectories)
+            .with_key_hints(self.show_key_hints)
+            .with_case_sensitive(self.case_sensitive)
+            .with_high_visibility(self.high_visibility)
+            .with_player_color(self.player_color)
+            .with_theme(self.theme)
+            .with_font(self.font);
+        self.game.sounds = sounds;
+        self.resume();
+        self.main_menu.show_resume(true);
+    }
+
+    /// Sets the game running again, from the start or after a pause.
+    fn resume(&mut self) {
+        self.game_state = Active;
+        self.first_frame = true;
+    }
+
+    fn draw_stats(&self, ctx: &mut Context, gctx: &mut event::GraphicsContext) -> GameResult {
+        let (screen_width, screen_height) = graphics::drawable_size(gctx);
+
+        let summary = self.game.summary();
+
+        let rendered = Text::new(
+            TextFragment::new(format!(
+                "Score: {}    Time: {}    WPM: {:.0}    Accuracy: {:.0}%",
+                summary.score,
+                format_clock(summary.duration),
+                summary.wpm,
+                summary.accuracy * 100.0,
+            ))
+                .font(self.font)
+                .scale(scaled(32.0, screen_height))
+                .color(ColorPalette::Fg.color_for(self.theme))
+        );
+
+        let position = Point2::new(
+            screen_width / 2.0 - rendered.width(ctx) / 2.0,
+            screen_height - rendered.height(ctx) - 35.0,
+        );
+
+        graphics::draw(ctx, gctx, &rendered, (position,))?;
+
+        Ok(())
+    }
+
+    fn draw_help(&self, ctx: &mut Context, gctx: &mut event::GraphicsContext) -> GameResult {
+        let (screen_width, screen_height) = graphics::drawable_size(gctx);
+
+        let title = Text::new(
+            TextFragment::new(HELP_TITLE)
+                .font(self.font)
+                .scale(scaled(96.0, screen_height))
+                .color(ColorPalette::Fg.color_for(self.theme))
+        );
+
+        let mut position = Point2::new(screen_width / 2.0 - title.width(ctx) / 2.0, screen_height / 6.0);
+        graphics::draw(ctx, gctx, &title, (position,))?;
+
+        let mut text = Text::new(
+            TextFragment::new(HELP_TEXT)
+                .font(self.font)
+                .scale(scaled(28.0, screen_height))
+                .color(ColorPalette::Fg.color_for(self.theme))
+        );
+        text.add(
+            TextFragment::new("\n\nPress Enter or Escape to go back")
+                .font(self.font)
+                .scale(scaled(28.0, screen_height))
+                .color(ColorPalette::Fg4.color_for(self.theme))
+        );
+
+        position.x = screen_width / 2.0 - text.width(ctx) / 2.0;
+        position.y += title.height(ctx) + 35.0;
+        graphics::draw(ctx, gctx, &text, (position,))?;
+
+        Ok(())
+    }
+
+    fn game_over(&mut self) {
+        self.game_state = GameOver;
+        self.main_menu.show_resume(false);
+
+        if self.game.score() > self.high_score {
+            self.high_score = self.game.score();
+            self.main_menu.set_high_score(self.high_score);
+
+            save_high_score(self.high_score);
+        }
+
+        if self.leaderboard.qualifies(self.game.score()) {
+            self.name_input.clear();
+            self.game_state = EnterName;
         }
     }
+
+    /// Puts the finished game on the leaderboard under the name typed so far.
+    fn submit_name(&mut self) {
+        let name = self.name_input.text().trim();
+        let name = if name.is_empty() { DEFAULT_PLAYER_NAME } else { name };
+
+        self.leaderboard.insert(name, self.game.score());
+        self.leaderboard.save();
+
+        self.game_state = GameOver;
+    }
+
+    fn draw_name_entry(&self, ctx: &mut Context, gctx: &mut event::GraphicsContext) -> GameResult {
+        let (screen_width, screen_height) = graphics::drawable_size(gctx);
+
+        let (r, g, b, _) = ColorPalette::TransparentBg.color_for(self.theme).to_rgba();
+        let shade = graphics::MeshBuilder::new()
+            .rectangle(DrawMode::fill(), Rect::new(0.0, 0.0, screen_width, screen_height), Color::from_rgba(r, g, b, SHADE_ALPHA))?
+            .build(ctx, gctx)?;
+        graphics::draw(ctx, gctx, &shade, (Point2::new(0.0, 0.0),))?;
+
+        let title = Text::new(
+            TextFragment::new("New High Score!")
+                .font(self.font)
+                .scale(scaled(72.0, screen_height))
+                .color(ColorPalette::BrightYellow.color_for(self.theme))
+        );
+
+        let mut position = Point2::new(screen_width / 2.0 - title.width(ctx) / 2.0, screen_height / 4.0);
+        graphics::draw(ctx, gctx, &title, (position,))?;
+
+        position.y += title.height(ctx) + 35.0;
+        let name_height = self.name_input.draw(
+            ctx,
+            gctx,
+            Point2::new(screen_width / 2.0, position.y),
+            self.font,
+            scaled(48.0, screen_height),
+            self.theme,
+        )?;
+
+        let prompt = Text::new(
+            TextFragment::new("Type your name and press Enter")
+                .font(self.font)
+                .scale(scaled(28.0, screen_height))
+                .color(ColorPalette::Fg4.color_for(self.theme))
+        );
+
+        position.x = screen_width / 2.0 - prompt.width(ctx) / 2.0;
+        position.y += name_height + 35.0;
+        graphics::draw(ctx, gctx, &prompt, (position,))?;
+
+        Ok(())
+    }
+
+    fn draw_leaderboard(&self, ctx: &mut Context, gctx: &mut event::GraphicsContext) -> GameResult {
+        let (screen_width, screen_height) = graphics::drawable_size(gctx);
+
+        let title = Text::new(
+            TextFragment::new(LEADERBOARD_TITLE)
+                .font(self.font)
+                .scale(scaled(96.0, screen_height))
+                .color(ColorPalette::Fg.color_for(self.theme))
+        );
+
+        let mut position = Point2::new(screen_width / 2.0 - title.width(ctx) / 2.0, screen_height / 8.0);
+        graphics::draw(ctx, gctx, &title, (position,))?;
+
+        let entries = if self.leaderboard.entries().is_empty() {
+            "No scores yet".to_string()
+        } else {
+            self.leaderboard.entries().iter()
+                .enumerate()
+                .map(|(i, (name, score))| format!("{:>2}. {:<width$} {:>6}", i + 1, name, score, width = NAME_MAX_LEN))
+                .collect::<Vec<_>>()
+                .join("\n")
+        };
+
+        let mut text = Text::new(
+            TextFragment::new(entries)
+                .font(self.font)
+                .scale(scaled(28.0, screen_height))
+                .color(ColorPalette::Fg.color_for(self.theme))
+        );
+        text.add(
+            TextFragment::new("\n\nPress Enter or Escape to go back")
+                .font(self.font)
+                .scale(scaled(28.0, screen_height))
+                .color(ColorPalette::Fg4.color_for(self.theme))
+        );
+
+        position.x = screen_width / 2.0 - text.width(ctx) / 2.0;
+        position.y += title.height(ctx) + 35.0;
+        graphics::draw(ctx, gctx, &text, (position,))?;
+
+        Ok(())
+    }
 }
 
 impl<'a> good_web_game::event::EventHandler for GameManager<'a> {
 
     fn update(&mut self, ctx: &mut Context, gctx: &mut event::GraphicsContext) -> Result<(), GameError> {
+        self.fps.push(ggez::timer::fps(ctx));
+
+        // good_web_game doesn't pass on focus events, but a minimized window or hidden tab stops
+        // getting frames, which shows up as one long one. The first frame after starting or
+        // resuming is skipped, since it can also cover loading the game or sitting in a menu.
+        let first_frame = std::mem::take(&mut self.first_frame);
+        let stalled = ggez::timer::delta(ctx).as_secs_f32() > FOCUS_LOST_FRAME_SECS;
+        if stalled && !first_frame {
+            self.game_state = on_focus_lost(self.game_state);
+        }
+
         match self.game_state {
-            Active => self.game.update(ctx, gctx),
+            Active => {
+                self.game.update(ctx, gctx)?;
+
+                if self.game.is_over() {
+                    self.game_over();
+                }
+
+                Ok(())
+            },
             MainMenu => self.main_menu.update(ctx, gctx),
+            DifficultySelect => self.difficulty_menu.update(ctx, gctx),
+            WordPackSelect => self.word_pack_menu.update(ctx, gctx),
+            Settings => self.settings_menu.update(ctx, gctx),
+            Help | HighScores => Ok(()),
+            // the game isn't updated while paused, so its words and animations hold still
             Paused => self.pause_menu.update(ctx, gctx),
+            ConfirmExit => self.exit_confirm_menu.update(ctx, gctx),
+            // the name is typed over the finished game, which stays frozen
+            EnterName => Ok(()),
+            GameOver => self.game_over_menu.update(ctx, gctx),
         }
     }
 
     fn draw(&mut self, ctx: &mut Context, gctx: &mut event::GraphicsContext) -> Result<(), GameError> {
         if let MainMenu = self.game_state {
             self.main_menu.draw(ctx, gctx)?;
+        } else if let DifficultySelect = self.game_state {
+            graphics::clear(ctx, gctx, ColorPalette::Bg.color_for(self.theme));
+            self.difficulty_menu.draw(ctx, gctx)?;
+        } else if let WordPackSelect = self.game_state {
+            graphics::clear(ctx, gctx, ColorPalette::Bg.color_for(self.theme));
+            self.word_pack_menu.draw(ctx, gctx)?;
+        } else if let Settings = self.game_state {
+            graphics::clear(ctx, gctx, ColorPalette::Bg.color_for(self.theme));
+            self.settings_menu.draw(ctx, gctx)?;
+        } else if let Help = self.game_state {
+            graphics::clear(ctx, gctx, ColorPalette::Bg.color_for(self.theme));
+            self.draw_help(ctx, gctx)?;
+        } else if let HighScores = self.game_state {
+            graphics::clear(ctx, gctx, ColorPalette::Bg.color_for(self.theme));
+            self.draw_leaderboard(ctx, gctx)?;
         } else {
             self.game.draw(ctx, gctx)?;
 
             if let Paused = self.game_state {
+                self.pause_menu.set_subtitle(Some(format!("Score: {}  Level: {}", self.game.score(), self.game.level())));
                 self.pause_menu.draw(ctx, gctx)?;
+            } else if let ConfirmExit = self.game_state {
+                self.exit_confirm_menu.draw(ctx, gctx)?;
+            } else if let EnterName = self.game_state {
+                self.draw_name_entry(ctx, gctx)?;
+            } else if let GameOver = self.game_state {
+                self.game_over_menu.draw(ctx, gctx)?;
+                self.draw_stats(ctx, gctx)?;
             }
         }
 
-        // debug
-        let (screen_width, screen_height) = graphics::drawable_size(gctx);
+        if self.debug_overlay {
+            let (screen_width, screen_height) = graphics::drawable_size(gctx);
+
+            let text = Text::new(TextFragment::new(format!("drawable_size: {screen_width}, {screen_height}")));
+            graphics::draw(ctx, gctx, &text, (Point2::new(0.0, 0.0),))?;
 
-        let text = Text::new(TextFragment::new(format!("drawable_size: {screen_width}, {screen_height}")));
-        graphics::draw(ctx, gctx, &text, (Point2::new(0.0, 0.0),))?;
+            let timing = Text::new(TextFragment::new(format!(
+                "fps: {:.1}, delta: {:.1}ms",
+                self.fps.average(),
+                ggez::timer::delta(ctx).as_secs_f64() * 1000.0,
+            )));
+            graphics::draw(ctx, gctx, &timing, (Point2::new(0.0, text.height(ctx)),))?;
+        }
 
         graphics::present(ctx, gctx)?;
 
@@ -97,6 +840,27 @@ impl<'a> good_web_game::event::EventHandler for GameManager<'a> {
             keymods: event::KeyMods,
             repeat: bool,
         ) {
+        if keycode == KeyCode::F3 {
+            self.debug_overlay = !self.debug_overlay;
+
+            return;
+        }
+
+        if keycode == KeyCode::F11 {
+            // the window then sends a resize event, which reflows the game
+            if let Some(fullscreen) = self.toggle_fullscreen(repeat) {
+                graphics::set_fullscreen(gctx, fullscreen);
+            }
+
+            return;
+        }
+
+        if let Some(state) = help_routing(self.game_state, keycode, self.main_menu.selected_action()) {
+            self.game_state = state;
+
+            return;
+        }
+
         match self.game_state {
             
             Active => {
@@ -109,45 +873,100 @@ impl<'a> good_web_game::event::EventHandler for GameManager<'a> {
             
             MainMenu => {
                 if keycode == KeyCode::Enter {
-                    let selected = self.main_menu.selected_item();
-                    
-                    if selected == NEW_GAME {
-                        
-                        let (screen_width, screen_height) = graphics::drawable_size(gctx);
-                        self.game = Game::new(screen_width, screen_height);
-                        self.game_state = Active;
-                        self.main_menu.show_resume(true);
+                    match self.main_menu.selected_action() {
+                        Some(MenuAction::NewGame) => self.select_difficulty(),
+                        Some(MenuAction::DailyChallenge) => self.daily_challenge(ctx, gctx, today_seed()),
+                        Some(MenuAction::Resume) => self.resume(),
+                        Some(MenuAction::Settings) => self.game_state = Settings,
+                        Some(MenuAction::Leaderboard) => self.game_state = HighScores,
+                        Some(MenuAction::Exit) => self.request_exit(ctx),
+                        _ => (),
+                    }
+                } else {
+                    self.main_menu.key_down_event(ctx, gctx, keycode, keymods, repeat)
+                }
+            },
 
-                    } else if selected == RESUME {
-                        
-                        self.game_state = Active;
+            DifficultySelect => {
+                if keycode == KeyCode::Enter {
 
-                    } else if selected == EXIT {
+                    if let Some(MenuAction::SelectDifficulty(difficulty)) = self.difficulty_menu.selected_action() {
+                        self.difficulty = difficulty;
+                        self.settings_menu.set_value(DIFFICULTY, difficulty.menu_item());
 
+                        self.select_word_pack();
                     }
+
+                    self.difficulty_menu.reset_selection();
+
+                } else if keycode == KeyCode::Escape {
+
+                    self.game_state = MainMenu;
+
+                    self.difficulty_menu.reset_selection();
+
                 } else {
-                    self.main_menu.key_down_event(ctx, gctx, keycode, keymods, repeat)
+
+                    self.difficulty_menu.key_down_event(ctx, gctx, keycode, keymods, repeat)
+                }
+            },
+
+            WordPackSelect => {
+                if keycode == KeyCode::Enter {
+
+                    if let Some(MenuAction::SelectWordPack(word_pack)) = self.word_pack_menu.selected_action() {
+                        self.word_pack = word_pack;
+
+                        self.new_game(ctx, gctx, self.difficulty);
+                    }
+
+                    self.word_pack_menu.reset_selection();
+
+                } else if keycode == KeyCode::Escape {
+
+                    self.select_difficulty();
+
+                    self.word_pack_menu.reset_selection();
+
+                } else {
+
+                    self.word_pack_menu.key_down_event(ctx, gctx, keycode, keymods, repeat)
+                }
+            },
+
+            Settings => {
+                if keycode == KeyCode::Escape {
+
+                    self.game_state = MainMenu;
+
+                    self.settings_menu.reset_selection();
+
+                    Settings::from_values(self.settings_menu.values()).save();
+
+                } else {
+
+                    self.settings_menu.key_down_event(ctx, gctx, keycode, keymods, repeat);
+
+                    self.apply_settings();
                 }
             },
 
             Paused => {
                 if keycode == KeyCode::Enter {
                     
-                    let selected = self.pause_menu.selected_item();
-
-                    if selected == RESUME {
-                        self.game_state = Active;
-                    } else if selected == EXIT {
-                        
-                    } else if selected == MAIN_MENU {
-                        self.game_state = MainMenu
+                    match self.pause_menu.selected_action() {
+                        Some(MenuAction::Resume) => self.resume(),
+                        Some(MenuAction::Restart) => self.restart(ctx, gctx),
+                        Some(MenuAction::Exit) => self.confirm_exit(),
+                        Some(MenuAction::MainMenu) => self.game_state = MainMenu,
+                        _ => (),
                     }
 
                     self.pause_menu.reset_selection();
 
                 } else if keycode == KeyCode::Escape {
                     
-                    self.game_state = Active;
+                    self.resume();
                     
                     self.pause_menu.reset_selection();
 
@@ -156,23 +975,464 @@ impl<'a> good_web_game::event::EventHandler for GameManager<'a> {
                     self.pause_menu.key_down_event(ctx, gctx, keycode, keymods, repeat)
                 }
             },
-        }
-        
-    }
-}
 
-pub struct Game {
-    player: Player,
+            // left by help_routing above
+            Help => (),
+
+            ConfirmExit => {
+                if keycode == KeyCode::Enter {
+
+                    match exit_confirmation_state(self.exit_confirm_menu.selected_action()) {
+                        Some(state) => self.game_state = state,
+                        None => self.request_exit(ctx),
+                    }
+
+                } else if keycode == KeyCode::Escape {
+
+                    self.game_state = Paused;
+
+                } else {
+
+                    self.exit_confirm_menu.key_down_event(ctx, gctx, keycode, keymods, repeat)
+                }
+            },
+
+            HighScores => {
+                if keycode == KeyCode::Enter || keycode == KeyCode::Escape {
+                    self.game_state = MainMenu;
+                }
+            },
+
+            EnterName => {
+                if keycode == KeyCode::Enter {
+                    self.submit_name();
+                } else if keycode == KeyCode::Escape {
+                    self.game_state = GameOver;
+                } else {
+                    self.name_input.key_down_event(keycode, keymods.contains(event::KeyMods::SHIFT));
+                }
+            },
+
+            GameOver => {
+                if keycode == KeyCode::Enter {
+
+                    match self.game_over_menu.selected_action() {
+                        Some(MenuAction::NewGame) => self.select_difficulty(),
+                        Some(MenuAction::MainMenu) => self.game_state = MainMenu,
+                        _ => (),
+                    }
+
+                    self.game_over_menu.reset_selection();
+
+                } else {
+
+                    self.game_over_menu.key_down_event(ctx, gctx, keycode, keymods, repeat)
+                }
+            },
+        }
+        
+    }
+
+    fn mouse_motion_event(
+            &mut self,
+            ctx: &mut Context,
+            gctx: &mut event::GraphicsContext,
+            x: f32,
+            y: f32,
+            dx: f32,
+            dy: f32,
+        ) {
+        match self.game_state {
+            Active | Help | HighScores | EnterName => (),
+            MainMenu => self.main_menu.mouse_motion_event(ctx, gctx, x, y, dx, dy),
+            DifficultySelect => self.difficulty_menu.mouse_motion_event(ctx, gctx, x, y, dx, dy),
+            WordPackSelect => self.word_pack_menu.mouse_motion_event(ctx, gctx, x, y, dx, dy),
+            Settings => self.settings_menu.mouse_motion_event(ctx, gctx, x, y, dx, dy),
+            Paused => self.pause_menu.mouse_motion_event(ctx, gctx, x, y, dx, dy),
+            ConfirmExit => self.exit_confirm_menu.mouse_motion_event(ctx, gctx, x, y, dx, dy),
+            GameOver => self.game_over_menu.mouse_motion_event(ctx, gctx, x, y, dx, dy),
+        }
+    }
+
+    fn mouse_button_down_event(
+            &mut self,
+            ctx: &mut Context,
+            gctx: &mut event::GraphicsContext,
+            button: MouseButton,
+            x: f32,
+            y: f32,
+        ) {
+        let hovered = match self.game_state {
+            Active | Help | HighScores | EnterName => None,
+            MainMenu => {
+                self.main_menu.mouse_button_down_event(ctx, gctx, button, x, y);
+                self.main_menu.item_at(x, y)
+            },
+            DifficultySelect => {
+                self.difficulty_menu.mouse_button_down_event(ctx, gctx, button, x, y);
+                self.difficulty_menu.item_at(x, y)
+            },
+            WordPackSelect => {
+                self.word_pack_menu.mouse_button_down_event(ctx, gctx, button, x, y);
+                self.word_pack_menu.item_at(x, y)
+            },
+            Settings => {
+                // clicking a row cycles its value rather than activating it
+                self.settings_menu.mouse_button_down_event(ctx, gctx, button, x, y);
+                self.apply_settings();
+
+                None
+            },
+            Paused => {
+                self.pause_menu.mouse_button_down_event(ctx, gctx, button, x, y);
+                self.pause_menu.item_at(x, y)
+            },
+            ConfirmExit => {
+                self.exit_confirm_menu.mouse_button_down_event(ctx, gctx, button, x, y);
+                self.exit_confirm_menu.item_at(x, y)
+            },
+            GameOver => {
+                self.game_over_menu.mouse_button_down_event(ctx, gctx, button, x, y);
+                self.game_over_menu.item_at(x, y)
+            },
+        };
+
+        // clicking an item activates it, just like pressing Enter
+        if button == MouseButton::Left && hovered.is_some() {
+            self.key_down_event(ctx, gctx, KeyCode::Enter, event::KeyMods::default(), false);
+        }
+    }
+
+    fn resize_event(&mut self, ctx: &mut Context, _gctx: &mut event::GraphicsContext, width: f32, height: f32) {
+        let old = graphics::screen_coordinates(ctx);
+
+        self.game.on_resize((old.w, old.h), (width, height));
+
+        if let Err(e) = graphics::set_screen_coordinates(ctx, Rect::new(0.0, 0.0, width, height)) {
+            eprintln!("warning: couldn't resize screen coordinates: {e}");
+        }
+    }
+}
+
+const COLLISION_DISTANCE: f32 = 10.0;
+const STARTING_LIVES: u32 = 3;
+/// Seconds the screen flashes red after losing a life.
+const HIT_FLASH_SECS: f32 = 0.3;
+/// Seconds the screen's border flashes red after a typo.
+const MISTAKE_FLASH_SECS: f32 = 0.2;
+/// Pixels wide the typo border is.
+const MISTAKE_BORDER_WIDTH: f32 = 6.0;
+/// Pixels the screen shakes by right after a collision.
+const SHAKE_MAGNITUDE: f32 = 8.0;
+/// Pixels per second the shake settles by.
+const SHAKE_DECAY: f32 = 20.0;
+
+fn player_position(screen_width: f32, screen_height: f32) -> Point2 {
+    Point2::new(screen_width / 2.0, screen_height - 30.0)
+}
+
+/// Velocity in pixels per second for a word spawned `r` away from the ring's center, heading
+/// for `target`.
+fn word_velocity(position: Point2, target: Point2, r: f32, speed: f32) -> Vector2 {
+    // seconds to reach the target, tuned back when words moved once per 60fps frame
+    let secs = (500.0 + r / 2.0) / 60.0 / speed;
+
+    Vector2::new(
+        (target.x - position.x) / secs, 
+        (target.y - position.y) / secs
+    )
+}
+
+/// How far, in pixels, past the screen edge a word may stray before it's dropped.
+const OFF_SCREEN_MARGIN: f32 = 100.0;
+
+/// Whether `position` lies more than `margin` outside a `width` by `height` screen.
+fn is_off_screen(position: Point2, width: f32, height: f32, margin: f32) -> bool {
+    position.x < -margin
+        || position.x > width + margin
+        || position.y < -margin
+        || position.y > height + margin
+}
+
+/// Seconds of movement a trajectory line looks ahead, so faster words get longer lines.
+const TRAJECTORY_SECS: f32 = 1.0;
+
+/// Where the trajectory line drawn ahead of a word at `position` moving at `velocity` ends.
+fn trajectory_end(position: Point2, velocity: Vector2) -> Point2 {
+    position + velocity * TRAJECTORY_SECS
+}
+
+/// The longest frame simulated in one step, so a long pause or a debugger stall doesn't make
+/// words jump forward.
+const MAX_FRAME_SECS: f32 = 0.1;
+
+fn clamp_delta(dt: f32) -> f32 {
+    dt.clamp(0.0, MAX_FRAME_SECS)
+}
+
+/// Seconds of "3, 2, 1, Go!" before words start moving.
+const COUNTDOWN_SECS: f32 = 3.0;
+const COUNTDOWN_LABELS: [&str; 4] = ["3", "2", "1", "Go!"];
+
+/// What the countdown shows with `remaining` seconds left.
+fn countdown_label(remaining: f32) -> &'static str {
+    let step = COUNTDOWN_SECS / COUNTDOWN_LABELS.len() as f32;
+    let i = ((COUNTDOWN_SECS - remaining) / step) as usize;
+
+    COUNTDOWN_LABELS[i.min(COUNTDOWN_LABELS.len() - 1)]
+}
+
+/// Formats whole seconds as `MM:SS`.
+fn format_clock(secs: f32) -> String {
+    let secs = secs.max(0.0) as u32;
+
+    format!("{:02}:{:02}", secs / 60, secs % 60)
+}
+
+/// Pixels per second the player moves while an arrow key is held.
+const PLAYER_SPEED: f32 = 300.0;
+
+/// Closest, in pixels, that freshly placed words may start to one another.
+const MIN_WORD_SPACING: f32 = 60.0;
+/// Random positions tried per word before settling for one that's too close.
+const PLACEMENT_ATTEMPTS: usize = 30;
+
+/// Scatters `words` in the band 50-300px beyond `radius` above `center`, each heading for `target`
+/// at the given `speed` multiplier.
+///
+/// Positions closer than `min_distance` to an already placed word are rejected and redrawn. If
+/// no acceptable spot turns up within `PLACEMENT_ATTEMPTS` tries, e.g. because the band is
+/// crowded, the last candidate is used anyway, so the spacing is best-effort.
+fn place_words(
+    words: &[String],
+    center: Point2,
+    radius: f32,
+    target: Point2,
+    speed: f32,
+    min_distance: f32,
+    rng: &mut impl Rng,
+) -> Vec<Word> {
+    let mut placed: Vec<Word> = vec![];
+
+    for word in words {
+        let mut candidate = (center, radius);
+
+        for _ in 0..PLACEMENT_ATTEMPTS {
+            let angle: f32 = rng.gen_range(0.0..=180.0);
+            let r = radius + rng.gen_range(50.0..300.0);
+            let theta = (angle - 180.0) * PI / 180.0;
+            let position = Point2::new(r * theta.cos() + center.x, r * theta.sin() + center.y);
+
+            candidate = (position, r);
+
+            let clear = placed.iter().all(|other| {
+                let dx = other.position().x - position.x;
+                let dy = other.position().y - position.y;
+
+                (dx * dx + dy * dy).sqrt() >= min_distance
+            });
+
+            if clear {
+                break;
+            }
+        }
+
+        let (position, r) = candidate;
+
+        placed.push(
+            Word::new(
+                word, 
+                position, 
+                word_velocity(position, target, r, speed),
+            )
+                .with_target(target)
+        );
+    }
+
+    placed
+}
+
+/// Where new words come in from.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum SpawnStrategy {
+    /// Scattered in a band beyond the ring of angle labels.
+    #[default]
+    Ring,
+    /// Along the top, left and right edges of the screen.
+    Edges,
+}
+
+/// Pixels beyond the screen's edge the nearest edge-spawned words start at.
+const EDGE_SPAWN_MARGIN: f32 = 40.0;
+/// Extra pixels further out edge-spawned words may start at, so they don't all arrive at once.
+const EDGE_SPAWN_STAGGER: f32 = 200.0;
+
+/// Makes a word for each of `words` just off the top edge of the screen, or the upper half of its
+/// left or right edge, heading for `target`.
+fn spawn_from_edges(
+    words: &[String],
+    screen_width: f32,
+    screen_height: f32,
+    target: Point2,
+    speed: f32,
+    rng: &mut impl Rng,
+) -> Vec<Word> {
+    words.iter()
+        .map(|word| {
+            let offset = EDGE_SPAWN_MARGIN + rng.gen_range(0.0..EDGE_SPAWN_STAGGER);
+
+            let position = match rng.gen_range(0..3) {
+                0 => Point2::new(rng.gen_range(0.0..screen_width), -offset),
+                1 => Point2::new(-offset, rng.gen_range(0.0..screen_height / 2.0)),
+                _ => Point2::new(screen_width + offset, rng.gen_range(0.0..screen_height / 2.0)),
+            };
+
+            let dx = target.x - position.x;
+            let dy = target.y - position.y;
+            let r = (dx * dx + dy * dy).sqrt();
+
+            Word::new(word, position, word_velocity(position, target, r, speed))
+                .with_target(target)
+        })
+        .collect()
+}
+
+/// Combo length that earns a freeze.
+const FREEZE_COMBO: u32 = 10;
+/// Seconds words stay frozen once a freeze is earned.
+const FREEZE_SECS: f32 = 3.0;
+
+/// Score multiplier for a combo of `combo` words: one extra for every ten in a row.
+fn combo_multiplier(combo: u32) -> usize {
+    1 + combo as usize / 10
+}
+
+/// How many words make up the given level.
+fn level_batch_size(level: usize) -> usize {
+    10 + 5 * level.saturating_sub(1)
+}
+
+/// Multiplier on word speed for the given level.
+fn level_speed(level: usize) -> f32 {
+    1.0 + 0.15 * level.saturating_sub(1) as f32
+}
+
+/// The results of a game, taken together.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct GameSummary {
+    pub score: usize,
+    pub level: usize,
+    pub words_completed: usize,
+    pub wpm: f64,
+    /// Fraction of keystrokes that were correct, from 0.0 to 1.0.
+    pub accuracy: f64,
+    /// Seconds of play.
+    pub duration: f32,
+}
+
+pub struct Game {
+    screen_width: f32,
+    screen_height: f32,
+    difficulty: Difficulty,
+    /// Every word the game can draw from, without duplicates.
+    word_list: Vec<String>,
+    level: usize,
+    keymap: Keymap,
+    player: Player,
     words: Vec<Word>,
-    reset_typed: usize,
-    keys_pressed: HashSet<KeyCode>,
+    pending: Vec<Word>,
+    spawn_timer: f64,
+    spawn_interval: f64,
+    active_target: Option<usize>,
+    score: usize,
+    words_completed: usize,
+    /// Words completed in a row without a mistyped key.
+    combo: u32,
+    /// Seconds left of the freeze earned by a long combo, during which words stop moving.
+    freeze_timer: f32,
+    /// How fast words move and animate relative to normal, for players who need more time.
+    time_scale: f32,
+    /// Whether to draw where each word is headed.
+    show_trajectories: bool,
+    /// Whether to show the keys to press beneath each word, for learning a layout.
+    show_key_hints: bool,
+    /// Whether capital letters need shift held, rather than any case matching.
+    case_sensitive: bool,
+    /// Whether typed letters stand out in bright yellow rather than fading into the background.
+    high_visibility: bool,
+    theme: Theme,
+    death_palette: DeathPalette,
+    spawn_strategy: SpawnStrategy,
+    font: Font,
+    stats: Stats,
+    lives: u32,
+    /// Seconds left of the red flash shown when a life is lost.
+    hit_flash: f32,
+    /// Keys pressed while typing a word that weren't its next character.
+    mistakes: usize,
+    /// Seconds left of the red border shown after a mistake.
+    mistake_flash: f32,
+    /// How far, in pixels, the screen currently shakes.
+    shake: f32,
+    /// Seconds left before play begins, or `None` once it has.
+    countdown: Option<f32>,
+    /// Seconds survived, not counting the countdown or time spent paused.
+    elapsed: f32,
+    /// Keys pressed since the last update, oldest first, with whether shift was held for each.
+    key_queue: VecDeque<(KeyCode, bool)>,
+    sounds: Sounds,
+    sound_queue: Vec<SoundEffect>,
+    particles: Vec<Particle>,
+    /// Drives word selection and placement, so a seeded game always plays out the same.
+    rng: StdRng,
+    /// Drives purely visual randomness, like particles and screen shake. Kept apart from `rng` so
+    /// that how many effects play doesn't change the words a seeded game deals out.
+    effects_rng: StdRng,
+    /// The seed this game was started from, if it was started from one.
+    seed: Option<u64>,
 }
 
 impl Game {
-    pub fn new(screen_width: f32, screen_height: f32) -> Self {
+    pub fn new(
+        screen_width: f32,
+        screen_height: f32,
+        difficulty: Difficulty,
+        spawn_strategy: SpawnStrategy,
+        word_list: &[String],
+    ) -> Self {
+        let rng = StdRng::from_rng(thread_rng()).expect("thread_rng never fails");
+
+        Self::with_rng(screen_width, screen_height, difficulty, spawn_strategy, word_list, rng)
+    }
+
+    /// A game whose word order and layout are fully determined by `seed`.
+    pub fn new_seeded(
+        screen_width: f32,
+        screen_height: f32,
+        difficulty: Difficulty,
+        spawn_strategy: SpawnStrategy,
+        word_list: &[String],
+        seed: u64,
+    ) -> Self {
+        let rng = StdRng::seed_from_u64(seed);
+        let mut game = Self::with_rng(screen_width, screen_height, difficulty, spawn_strategy, word_list, rng);
+        game.seed = Some(seed);
+
+        game
+    }
+
+    fn with_rng(
+        screen_width: f32,
+        screen_height: f32,
+        difficulty: Difficulty,
+        spawn_strategy: SpawnStrategy,
+        word_list: &[String],
+        mut rng: StdRng,
+    ) -> Self {
+        let effects_rng = StdRng::seed_from_u64(rng.gen());
 
         let player_radius = 4.0; 
-        let player_position = Point2::new(screen_width / 2.0, screen_height - 30.0);
+        let player_position = player_position(screen_width, screen_height);
         
         let mut words = vec![];
         let radius = screen_height / 1.7;
@@ -199,300 +1459,1652 @@ impl Game {
             let y = radius * theta.sin() + center_y * 2.0;
 
             let word = Word::new(label, Point2::new(x, y), Vector2::new(0.0, 0.0))
-                .with_color(ColorPalette::Bg2);
+                .with_color(ColorPalette::Bg2)
+                .decorative();
 
             words.push(word);
         }
 
-        let mut word_list = Vec::from(WORD_LIST);
-        word_list.shuffle(&mut thread_rng());
+        let keymap = Keymap::default();
 
-        for (i, word) in word_list.iter().enumerate() {
-            let angle = rand::thread_rng().gen_range(0.0..=180.0);
-            let rand_r = rand::thread_rng().gen_range(50.0..300.0);
-            let r = radius + i as f32 * rand_r;
-            let theta = (angle - 180.0) * PI / 180.0;
-            let x = r * theta.cos() + center_x;
-            let y = r * theta.sin() + center_y;
+        // a list with nothing to type would leave the game sitting idle
+        let typeable = if has_typeable_words(word_list, &keymap) {
+            typeable_words(word_list, &keymap)
+        } else {
+            if !word_list.is_empty() {
+                eprintln!("warning: none of the {} words can be typed, using the built-in words", word_list.len());
+            }
 
-            words.push(Word::new(
-                word, 
-                Point2::new(x, y), 
-                Vector2::new(
-                    (player_position.x - x) / (500.0 + r / 2.0), 
-                    (player_position.y - y) / (500.0 + r / 2.0)
-                ))
-            );
-        }
+            typeable_words(&builtin_word_list(), &keymap)
+        };
+        let word_list = filter_word_length(typeable, difficulty.word_length_range());
 
-        Self {
-            player: Player::new(player_position, player_radius),
+        let mut game = Self {
+            screen_width,
+            screen_height,
+            difficulty,
+            word_list,
+            level: 1,
+            keymap,
+            player: Player::with_default_precision(player_position, player_radius),
             words,
-            reset_typed: 0,
-            keys_pressed: HashSet::new(),
+            pending: vec![],
+            spawn_timer: 0.0,
+            spawn_interval: difficulty.spawn_interval(),
+            active_target: None,
+            score: 0,
+            words_completed: 0,
+            combo: 0,
+            freeze_timer: 0.0,
+            time_scale: 1.0,
+            show_trajectories: false,
+            show_key_hints: false,
+            case_sensitive: false,
+            high_visibility: false,
+            theme: Theme::default(),
+            death_palette: DeathPalette::default(),
+            spawn_strategy,
+            font: Font::default(),
+            stats: Stats::new(),
+            lives: STARTING_LIVES,
+            hit_flash: 0.0,
+            mistakes: 0,
+            mistake_flash: 0.0,
+            shake: 0.0,
+            countdown: Some(COUNTDOWN_SECS),
+            elapsed: 0.0,
+            key_queue: VecDeque::new(),
+            sounds: Sounds::default(),
+            sound_queue: vec![],
+            particles: vec![],
+            rng,
+            effects_rng,
+            seed: None,
+        };
+
+        game.spawn_batch();
+
+        game
+    }
+
+    /// Queues up this level's words just off screen, each heading for the player.
+    fn spawn_batch(&mut self) {
+        let radius = self.screen_height / 1.7;
+        let center_x = self.screen_width / 2.0;
+        let center_y = self.screen_height / 2.0 - 30.0;
+        let speed = self.difficulty.speed() * level_speed(self.level);
+
+        let mut word_list = self.word_list.clone();
+        word_list.shuffle(&mut self.rng);
+        word_list.truncate(level_batch_size(self.level));
+
+        let placed = match self.spawn_strategy {
+            SpawnStrategy::Ring => place_words(
+                &word_list,
+                Point2::new(center_x, center_y),
+                radius,
+                self.player.position,
+                speed,
+                MIN_WORD_SPACING,
+                &mut self.rng,
+            ),
+            SpawnStrategy::Edges => spawn_from_edges(
+                &word_list,
+                self.screen_width,
+                self.screen_height,
+                self.player.position,
+                speed,
+                &mut self.rng,
+            ),
+        };
+        self.pending.extend(placed.into_iter().map(|word| word.with_theme(self.theme).with_death_palette(self.death_palette)));
+    }
+
+    /// Whether every word of the current level has been typed and finished dying.
+    pub fn all_words_cleared(&self) -> bool {
+        self.pending.is_empty()
+            && self.words.iter()
+                .filter(|word| !word.is_decorative())
+                .all(|word| word.state == WordState::Dead)
+    }
+
+    /// Clears away the finished words and queues a bigger, faster batch.
+    pub fn next_level(&mut self) {
+        self.level += 1;
+
+        self.words.retain(|word| word.is_decorative());
+        self.active_target = None;
+        self.spawn_timer = 0.0;
+
+        self.spawn_batch();
+    }
+
+    pub fn level(&self) -> usize {
+        self.level
+    }
+
+    pub fn with_keymap(mut self, keymap: Keymap) -> Self {
+        self.keymap = keymap;
+
+        self
+    }
+
+    pub fn with_time_scale(mut self, time_scale: f32) -> Self {
+        self.time_scale = time_scale;
+
+        self
+    }
+
+    pub fn with_trajectories(mut self, show_trajectories: bool) -> Self {
+        self.show_trajectories = show_trajectories;
+
+        self
+    }
+
+    pub fn with_key_hints(mut self, show_key_hints: bool) -> Self {
+        self.show_key_hints = show_key_hints;
+
+        self
+    }
+
+    pub fn with_case_sensitive(mut self, case_sensitive: bool) -> Self {
+        self.case_sensitive = case_sensitive;
+
+        self
+    }
+
+    pub fn with_high_visibility(mut self, high_visibility: bool) -> Self {
+        self.high_visibility = high_visibility;
+
+        self
+    }
+
+    /// Themes how words flash as they die, including those already waiting to spawn.
+    pub fn with_death_palette(mut self, death_palette: DeathPalette) -> Self {
+        self.death_palette = death_palette;
+        self.pending = std::mem::take(&mut self.pending).into_iter()
+            .map(|word| word.with_death_palette(death_palette))
+            .collect();
+
+        self
+    }
+
+    pub fn with_theme(mut self, theme: Theme) -> Self {
+        self.set_theme(theme);
+
+        self
+    }
+
+    /// Recolors everything for `theme`, including the words already on screen or waiting to spawn.
+    pub fn set_theme(&mut self, theme: Theme) {
+        self.theme = theme;
+
+        for word in self.words.iter_mut().chain(self.pending.iter_mut()) {
+            word.set_theme(theme);
         }
+    }
+
+    pub fn with_player_color(mut self, color: ColorPalette) -> Self {
+        self.player.set_color(color);
 
+        self
     }
-}
 
-impl event::EventHandler for Game {
-    fn update(&mut self, 
-        ctx: &mut Context,
-        gctx: &mut event::GraphicsContext,
-    ) -> GameResult {
+    pub fn with_font(mut self, font: Font) -> Self {
+        self.font = font;
 
-        let new_keypress = {
-            let mut val = None;
-            for key_code in pressed_keys(ctx) {
-                if self.keys_pressed.contains(key_code) == false {
-                    val = Some(key_code.clone());
-                    break;
-                }
+        self
+    }
+
+    /// Queues a key press for the next update. Every press counts, even several of the same key
+    /// within one frame, e.g. the double "g" in "egg".
+    fn buffer_key(&mut self, keycode: KeyCode, shift: bool, repeat: bool) {
+        // holding a key down shouldn't type it over and over
+        if !repeat {
+            self.key_queue.push_back((keycode, shift));
+        }
+    }
+
+    /// Advances the game by one frame, feeding it every key queued since the last one.
+    fn step_queued(&mut self, dt: f32) {
+        let keypresses: Vec<(KeyCode, bool)> = self.key_queue.drain(..).collect();
+
+        self.step(&keypresses, dt);
+    }
+
+    /// Advances the simulation by `dt` seconds, as though `new_keys` had just been pressed, each
+    /// alongside whether shift was held for it.
+    ///
+    /// Needs no window or graphics context, so the game can be driven headlessly, e.g. from
+    /// tests. Drawing and sound playback happen separately in the `EventHandler` impl.
+    pub fn step(&mut self, new_keys: &[(KeyCode, bool)], dt: f32) {
+        if let Some(remaining) = self.countdown {
+            let remaining = remaining - dt;
+            self.countdown = (remaining > 0.0).then_some(remaining);
+
+            return;
+        }
+
+        let dt_f64 = f64::from(dt);
+
+        self.elapsed += dt;
+        self.stats.advance(dt_f64);
+
+        // slow motion stretches out everything the player has to react to
+        let scaled_dt = f64::from(dt * self.time_scale);
+
+        self.spawn_timer += scaled_dt;
+
+        while self.spawn_timer >= self.spawn_interval {
+            self.spawn_timer -= self.spawn_interval;
+
+            if let Some(word) = self.pending.pop() {
+                self.words.push(word);
             }
-            
-            val
-        };
+        }
 
-        self.keys_pressed = pressed_keys(ctx).clone();
+        for (key_pressed, shift) in new_keys {
+            self.type_key(*key_pressed, *shift);
+        }
+
+        let mut completed = vec![];
+        // the time scale is already part of `scaled_dt`
+        let speed = if self.freeze_timer > 0.0 { 0.0 } else { 1.0 };
 
         for word in self.words.iter_mut() {
-            if self.reset_typed > 0 {
-                if word.state == WordState::Active {
-                    word.num_typed = 0;
-                }
-            } else {
+            if word.tick(scaled_dt, speed) == WordEvent::Completed {
+                self.sound_queue.push(SoundEffect::WordComplete);
+                self.particles.extend(burst(word.position(), &mut self.effects_rng));
+                completed.push(word.len());
+            }
+        }
 
-                let old_state = word.state;
+        for len in completed {
+            self.register_completion();
+            self.score += len * 10 * combo_multiplier(self.combo);
+        }
 
-                word.update(ctx, gctx, new_keypress)?;
-                
-                if old_state == WordState::Active && word.state == WordState::Typed {
-                    self.reset_typed = 2;
-                    break;
-                }
-            }
+        self.freeze_timer = (self.freeze_timer - dt).max(0.0);
+        self.hit_flash = (self.hit_flash - dt).max(0.0);
+        self.mistake_flash = (self.mistake_flash - dt).max(0.0);
+        self.shake = (self.shake - SHAKE_DECAY * dt).max(0.0);
+        self.check_collisions();
+        self.cull_off_screen();
+
+        self.release_target();
+        self.remove_dead();
+
+        if self.all_words_cleared() {
+            self.next_level();
         }
 
-        self.reset_typed = self.reset_typed.saturating_sub(1);
+        for particle in self.particles.iter_mut() {
+            particle.tick(scaled_dt);
+        }
 
-        Ok(())
+        self.particles.retain(|particle| !particle.is_expired());
     }
 
-    fn draw(&mut self, 
-        ctx: &mut Context,
-        gctx: &mut event::GraphicsContext,
-    ) -> GameResult {
+    /// Moves everything on screen proportionally when the window changes from `old` to `new`
+    /// (width, height), so words keep their relative place and heading.
+    pub fn on_resize(&mut self, old: (f32, f32), new: (f32, f32)) {
+        let (old_width, old_height) = old;
+        let (new_width, new_height) = new;
 
-        graphics::clear(ctx, gctx, ColorPalette::Bg.into());
+        if old_width <= 0.0 || old_height <= 0.0 {
+            return;
+        }
 
-        for word in self.words.iter_mut() {
-            word.draw(ctx, gctx)?;
+        let scale_x = new_width / old_width;
+        let scale_y = new_height / old_height;
+
+        for word in self.words.iter_mut().chain(self.pending.iter_mut()) {
+            word.rescale(scale_x, scale_y);
         }
 
-        self.player.draw(ctx, gctx)?;
-        
-        Ok(())
+        for particle in self.particles.iter_mut() {
+            particle.position.x *= scale_x;
+            particle.position.y *= scale_y;
+        }
+
+        self.player.position = Point2::new(
+            self.player.position.x * scale_x,
+            player_position(new_width, new_height).y,
+        );
+
+        self.screen_width = new_width;
+        self.screen_height = new_height;
     }
-}
 
-struct Player {
-    position: Point2,
-    radius: f32,
-    precision: f32,
-}
+    pub fn seed(&self) -> Option<u64> {
+        self.seed
+    }
 
-impl Player {
-    fn new(position: Point2, radius: f32) -> Self {
-        Self { position, radius, precision: 0.01 }
+    pub fn difficulty(&self) -> Difficulty {
+        self.difficulty
+    }
 
+    /// Every word on screen, including the ring's decorative labels.
+    pub fn words(&self) -> &[Word] {
+        &self.words
     }
-}
 
-impl EventHandler for Player {
-    fn update(&mut self, _ctx: &mut Context, _gctx: &mut event::GraphicsContext) -> Result<(), GameError> {
-        Ok(())
+    pub fn score(&self) -> usize {
+        self.score
     }
 
-    fn draw(&mut self, ctx: &mut Context, gctx: &mut event::GraphicsContext) -> Result<(), GameError> {
-        let image = graphics::MeshBuilder::new()
-            .circle(DrawMode::fill(), Point2::new(-1.0 * self.radius,  -1.0 * self.radius), self.radius, self.precision, ColorPalette::Orange.into())?
-            .build(ctx, gctx)?;
+    pub fn elapsed_secs(&self) -> f32 {
+        self.elapsed
+    }
 
-        graphics::draw(ctx, gctx, &image, (self.position,))?;
+    pub fn stats(&self) -> &Stats {
+        &self.stats
+    }
 
-        Ok(())
+    /// How the run went, for the game over screen.
+    pub fn summary(&self) -> GameSummary {
+        GameSummary {
+            score: self.score,
+            level: self.level,
+            words_completed: self.words_completed,
+            wpm: self.stats.wpm(),
+            accuracy: self.stats.accuracy(),
+            duration: self.elapsed,
+        }
+    }
+
+    /// Runs `steps` steps of `dt` seconds each, pressing `keys` on the first, and returns the
+    /// score and the number of words still in play, e.g. for benchmarking the update path.
+    pub fn simulate(&mut self, keys: &[(KeyCode, bool)], dt: f32, steps: usize) -> (usize, usize) {
+        for i in 0..steps {
+            self.step(if i == 0 { keys } else { &[] }, dt);
+        }
+
+        let live_words = self.words.iter()
+            .filter(|word| word.state == WordState::Active && !word.is_decorative())
+            .count();
+
+        (self.score, live_words)
+    }
+
+    pub fn lives(&self) -> u32 {
+        self.lives
+    }
+
+    /// The game ends once the player has run out of lives.
+    pub fn is_over(&self) -> bool {
+        self.lives == 0
+    }
+
+    /// Costs a life for each untyped word that has reached the player, destroying the word.
+    fn check_collisions(&mut self) {
+        let player_position = self.player.visual_center();
+
+        for word in self.words.iter_mut() {
+            if word.state != WordState::Active || word.is_decorative() {
+                continue;
+            }
+
+            let dx = word.position().x - player_position.x;
+            let dy = word.position().y - player_position.y;
+
+            if (dx * dx + dy * dy).sqrt() < COLLISION_DISTANCE {
+                word.state = WordState::Dead;
+
+                self.lives = self.lives.saturating_sub(1);
+                self.hit_flash = HIT_FLASH_SECS;
+                self.shake = SHAKE_MAGNITUDE;
+            }
+        }
+    }
+
+    /// Counts a finished word toward the combo, freezing the words every `FREEZE_COMBO` in a row.
+    fn register_completion(&mut self) {
+        self.words_completed += 1;
+        self.combo += 1;
+
+        if self.combo.is_multiple_of(FREEZE_COMBO) {
+            self.freeze_timer = FREEZE_SECS;
+        }
+    }
+
+    /// Counts a typo and breaks the combo.
+    ///
+    /// Only keys pressed while a word is targeted count: with no target, a key matching no word
+    /// may just be the player looking for one to start on.
+    fn register_mistake(&mut self) {
+        self.combo = 0;
+        self.mistakes += 1;
+        self.mistake_flash = MISTAKE_FLASH_SECS;
+    }
+
+    pub fn mistakes(&self) -> usize {
+        self.mistakes
+    }
+
+    /// Drops words that have finished dying, keeping `active_target` pointed at the same word.
+    fn remove_dead(&mut self) {
+        if let Some(i) = self.active_target {
+            let dead_before = self.words[..i].iter()
+                .filter(|word| word.state == WordState::Dead)
+                .count();
+
+            self.active_target = Some(i - dead_before);
+        }
+
+        self.words.retain(|word| word.state != WordState::Dead);
+    }
+
+    /// Retires words that have drifted off screen and are only getting further from the player,
+    /// e.g. ones the player dodged. Words that spawned off screen and are still incoming stay.
+    fn cull_off_screen(&mut self) {
+        let player_position = self.player.position;
+
+        for word in self.words.iter_mut() {
+            if word.state != WordState::Active || word.is_decorative() {
+                continue;
+            }
+
+            let position = word.position();
+            let velocity = word.velocity();
+            let receding = velocity.x * (player_position.x - position.x)
+                + velocity.y * (player_position.y - position.y) < 0.0;
+
+            if receding && is_off_screen(position, self.screen_width, self.screen_height, OFF_SCREEN_MARGIN) {
+                word.state = WordState::Dead;
+            }
+        }
+    }
+
+    /// Feeds a keypress to the targeted word, or, if there is no target, locks onto the
+    /// first word the keypress advances.
+    fn type_key(&mut self, key_pressed: KeyCode, shift: bool) {
+        let targeted = self.active_target.is_some();
+        let correct = match self.active_target {
+            Some(i) => {
+                self.words[i].type_key(key_pressed, shift, self.case_sensitive, &self.keymap, self.elapsed) == WordEvent::CharTyped
+            },
+
+            // with nothing targeted there's no progress for Backspace to undo
+            None if key_pressed == KeyCode::Backspace => false,
+
+            None => {
+                for (i, word) in self.words.iter_mut().enumerate() {
+                    if word.state != WordState::Active {
+                        continue;
+                    }
+
+                    if word.type_key(key_pressed, shift, self.case_sensitive, &self.keymap, self.elapsed) == WordEvent::CharTyped {
+                        self.active_target = Some(i);
+                        break;
+                    }
+                }
+
+                self.active_target.is_some()
+            },
+        };
+
+        if self.keymap.is_typing_key(key_pressed) {
+            self.stats.record_keystroke(correct);
+
+            if targeted && !correct {
+                self.register_mistake();
+            }
+        }
+
+        if correct {
+            self.sound_queue.push(SoundEffect::Keypress);
+        }
+
+        self.release_target();
+    }
+
+    /// Clears the target once its word is finished or its progress has been undone. A word counts
+    /// as finished as soon as its last character is typed, so the next key can start another word
+    /// in the same frame.
+    fn release_target(&mut self) {
+        if let Some(i) = self.active_target {
+            let word = &self.words[i];
+
+            if word.state != WordState::Active || word.num_typed == 0 || word.num_typed == word.len() {
+                self.active_target = None;
+            }
+        }
+    }
+}
+
+impl event::EventHandler for Game {
+    fn update(&mut self, 
+        ctx: &mut Context,
+        _gctx: &mut event::GraphicsContext,
+    ) -> GameResult {
+
+        let keys_pressed = pressed_keys(ctx);
+
+        let dt = clamp_delta(ggez::timer::delta(ctx).as_secs_f32());
+
+        // only the arrow keys steer, since letters are needed for typing
+        let direction = match (keys_pressed.contains(&KeyCode::Left), keys_pressed.contains(&KeyCode::Right)) {
+            (true, false) => -1.0,
+            (false, true) => 1.0,
+            _ => 0.0,
+        };
+
+        self.player.move_by(direction * PLAYER_SPEED * dt, self.screen_width);
+
+        self.step_queued(dt);
+
+        for effect in self.sound_queue.drain(..) {
+            self.sounds.play(ctx, effect)?;
+        }
+
+        Ok(())
+    }
+
+    fn draw(&mut self, 
+        ctx: &mut Context,
+        gctx: &mut event::GraphicsContext,
+    ) -> GameResult {
+
+        graphics::clear(ctx, gctx, ColorPalette::Bg.color_for(self.theme));
+
+        // shake the playfield by shifting the screen under it, leaving the HUD in place
+        let screen = graphics::screen_coordinates(ctx);
+
+        if self.shake > 0.0 {
+            let dx = self.effects_rng.gen_range(-1.0..=1.0) * self.shake;
+            let dy = self.effects_rng.gen_range(-1.0..=1.0) * self.shake;
+
+            graphics::set_screen_coordinates(ctx, Rect::new(screen.x + dx, screen.y + dy, screen.w, screen.h))?;
+        }
+
+        if self.show_trajectories {
+            let mut builder = graphics::MeshBuilder::new();
+            let mut lines = 0;
+            let mut color: Color = ColorPalette::Fg4.color_for(self.theme);
+            color.a = 0.25;
+
+            for word in self.words.iter().filter(|word| word.state == WordState::Active && !word.is_decorative()) {
+                let end = trajectory_end(word.position(), word.velocity());
+
+                if end != word.position() {
+                    builder.line(&[word.position(), end], 1.0, color)?;
+                    lines += 1;
+                }
+            }
+
+            // building an empty mesh fails
+            if lines > 0 {
+                let mesh = builder.build(ctx, gctx)?;
+                graphics::draw(ctx, gctx, &mesh, (Point2::new(0.0, 0.0),))?;
+            }
+        }
+
+        let hint_keymap = self.show_key_hints.then_some(&self.keymap);
+
+        for word in self.words.iter_mut() {
+            word.draw(ctx, gctx, self.font, hint_keymap, self.high_visibility)?;
+        }
+
+        if !self.particles.is_empty() {
+            let mut builder = graphics::MeshBuilder::new();
+
+            for particle in self.particles.iter() {
+                builder.circle(DrawMode::fill(), particle.position, 2.0, 0.1, particle.color.color_for(self.theme))?;
+            }
+
+            let mesh = builder.build(ctx, gctx)?;
+            graphics::draw(ctx, gctx, &mesh, (Point2::new(0.0, 0.0),))?;
+        }
+
+        self.player.draw(ctx, gctx, self.theme)?;
+
+        graphics::set_screen_coordinates(ctx, screen)?;
+
+        let (screen_width, screen_height) = graphics::drawable_size(gctx);
+
+        if self.lives() > 0 {
+            let mut builder = graphics::MeshBuilder::new();
+
+            for i in 0..self.lives() {
+                let position = Point2::new(20.0 + i as f32 * 16.0, screen_height - 20.0);
+                builder.circle(DrawMode::fill(), position, 5.0, 0.1, self.player.color.color_for(self.theme))?;
+            }
+
+            let mesh = builder.build(ctx, gctx)?;
+            graphics::draw(ctx, gctx, &mesh, (Point2::new(0.0, 0.0),))?;
+        }
+
+        if self.freeze_timer > 0.0 {
+            let mut color: Color = ColorPalette::Blue.color_for(self.theme);
+            color.a = 0.15;
+
+            let tint = graphics::MeshBuilder::new()
+                .rectangle(DrawMode::fill(), Rect::new(0.0, 0.0, screen_width, screen_height), color)?
+                .build(ctx, gctx)?;
+
+            graphics::draw(ctx, gctx, &tint, (Point2::new(0.0, 0.0),))?;
+        }
+
+        if self.hit_flash > 0.0 {
+            let mut color: Color = ColorPalette::Red.color_for(self.theme);
+            color.a = 0.4 * self.hit_flash / HIT_FLASH_SECS;
+
+            let flash = graphics::MeshBuilder::new()
+                .rectangle(DrawMode::fill(), Rect::new(0.0, 0.0, screen_width, screen_height), color)?
+                .build(ctx, gctx)?;
+
+            graphics::draw(ctx, gctx, &flash, (Point2::new(0.0, 0.0),))?;
+        }
+
+        if self.mistake_flash > 0.0 {
+            let mut color: Color = ColorPalette::Red.color_for(self.theme);
+            color.a = self.mistake_flash / MISTAKE_FLASH_SECS;
+
+            let inset = MISTAKE_BORDER_WIDTH / 2.0;
+            let border = graphics::MeshBuilder::new()
+                .rectangle(
+                    DrawMode::stroke(MISTAKE_BORDER_WIDTH),
+                    Rect::new(inset, inset, screen_width - MISTAKE_BORDER_WIDTH, screen_height - MISTAKE_BORDER_WIDTH),
+                    color,
+                )?
+                .build(ctx, gctx)?;
+
+            graphics::draw(ctx, gctx, &border, (Point2::new(0.0, 0.0),))?;
+        }
+
+        let typos = Text::new(
+            TextFragment::new(format!("Typos: {}", self.mistakes))
+                .font(self.font)
+                .scale(scaled(24.0, screen_height))
+                .color(ColorPalette::Fg4.color_for(self.theme))
+        );
+
+        graphics::draw(ctx, gctx, &typos, (Point2::new(10.0, 10.0),))?;
+
+        let score = Text::new(
+            TextFragment::new(format!("Score: {}", self.score))
+                .font(self.font)
+                .scale(scaled(24.0, screen_height))
+                .color(ColorPalette::Fg.color_for(self.theme))
+        );
+
+        graphics::draw(
+            ctx,
+            gctx,
+            &score,
+            (Point2::new(screen_width - score.width(ctx) - 10.0, 10.0),),
+        )?;
+
+        let level = Text::new(
+            TextFragment::new(format!("Level: {}", self.level()))
+                .font(self.font)
+                .scale(scaled(24.0, screen_height))
+                .color(ColorPalette::Fg4.color_for(self.theme))
+        );
+
+        graphics::draw(
+            ctx,
+            gctx,
+            &level,
+            (Point2::new(screen_width - level.width(ctx) - 10.0, 10.0 + score.height(ctx)),),
+        )?;
+
+        if self.combo > 0 {
+            let combo = Text::new(
+                TextFragment::new(format!("Combo: {} (x{})", self.combo, combo_multiplier(self.combo)))
+                    .font(self.font)
+                    .scale(scaled(24.0, screen_height))
+                    .color(ColorPalette::BrightYellow.color_for(self.theme))
+            );
+
+            graphics::draw(
+                ctx,
+                gctx,
+                &combo,
+                (Point2::new(screen_width - combo.width(ctx) - 10.0, 10.0 + score.height(ctx) + level.height(ctx)),),
+            )?;
+        }
+
+        let clock = Text::new(
+            TextFragment::new(format_clock(self.elapsed_secs()))
+                .font(self.font)
+                .scale(scaled(24.0, screen_height))
+                .color(ColorPalette::Fg4.color_for(self.theme))
+        );
+
+        graphics::draw(
+            ctx,
+            gctx,
+            &clock,
+            (Point2::new(screen_width / 2.0 - clock.width(ctx) / 2.0, 10.0),),
+        )?;
+
+        if let Some(remaining) = self.countdown {
+            let label = Text::new(
+                TextFragment::new(countdown_label(remaining))
+                    .font(self.font)
+                    .scale(scaled(144.0, screen_height))
+                    .color(ColorPalette::BrightYellow.color_for(self.theme))
+            );
+
+            let position = Point2::new(
+                screen_width / 2.0 - label.width(ctx) / 2.0,
+                screen_height / 2.0 - label.height(ctx) / 2.0,
+            );
+
+            graphics::draw(ctx, gctx, &label, (position,))?;
+        }
+        
+        Ok(())
+    }
+
+    fn key_down_event(
+            &mut self,
+            _ctx: &mut Context,
+            _gctx: &mut event::GraphicsContext,
+            keycode: KeyCode,
+            keymods: event::KeyMods,
+            repeat: bool,
+        ) {
+        self.buffer_key(keycode, keymods.contains(event::KeyMods::SHIFT), repeat);
+    }
+}
+
+/// Where the player's circle is centered within its mesh. Drawing the mesh at `position` then
+/// puts the circle's center right on `position`, which is what collisions measure from.
+const PLAYER_MESH_CENTER: Point2 = Point2::new(0.0, 0.0);
+
+/// Default tessellation tolerance for the player's circle.
+const PLAYER_PRECISION: f32 = 0.01;
+const PLAYER_PRECISION_RANGE: std::ops::RangeInclusive<f32> = 0.001..=0.5;
+
+struct Player {
+    position: Point2,
+    radius: f32,
+    precision: f32,
+    color: ColorPalette,
+    /// Built on the first draw, since that's the first time a context is available, and
+    /// rebuilt if the theme changes its color.
+    mesh: Option<(Mesh, Color)>,
+}
+
+impl Player {
+    /// A player whose circle is tessellated with `precision`, clamped to `PLAYER_PRECISION_RANGE`
+    /// since tiny values explode the vertex count and large ones leave a visible polygon. A NaN or
+    /// infinite precision falls back to `PLAYER_PRECISION`.
+    fn new(position: Point2, radius: f32, precision: f32) -> Self {
+        let precision = if precision.is_finite() {
+            precision.clamp(*PLAYER_PRECISION_RANGE.start(), *PLAYER_PRECISION_RANGE.end())
+        } else {
+            PLAYER_PRECISION
+        };
+
+        Self { position, radius, precision, color: ColorPalette::Orange, mesh: None }
+    }
+
+    fn with_default_precision(position: Point2, radius: f32) -> Self {
+        Self::new(position, radius, PLAYER_PRECISION)
+    }
+
+    fn set_color(&mut self, color: ColorPalette) {
+        self.color = color;
+    }
+
+    /// Moves horizontally by `dx`, keeping the whole circle on screen.
+    fn move_by(&mut self, dx: f32, screen_width: f32) {
+        let max_x = (screen_width - self.radius).max(self.radius);
+
+        self.position.x = (self.position.x + dx).clamp(self.radius, max_x);
+    }
+
+    /// Where the center of the drawn circle ends up on screen.
+    fn visual_center(&self) -> Point2 {
+        Point2::new(self.position.x + PLAYER_MESH_CENTER.x, self.position.y + PLAYER_MESH_CENTER.y)
+    }
+
+    fn draw(&mut self, ctx: &mut Context, gctx: &mut event::GraphicsContext, theme: Theme) -> GameResult {
+        let color = self.color.color_for(theme);
+
+        if !matches!(&self.mesh, Some((_, mesh_color)) if *mesh_color == color) {
+            let mesh = graphics::MeshBuilder::new()
+                .circle(DrawMode::fill(), PLAYER_MESH_CENTER, self.radius, self.precision, color)?
+                .build(ctx, gctx)?;
+
+            self.mesh = Some((mesh, color));
+        }
+
+        if let Some((mesh, _)) = &self.mesh {
+            graphics::draw(ctx, gctx, mesh, (self.position,))?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::particle::PARTICLES_PER_BURST;
+
+    fn test_game(words: &[&str]) -> Game {
+        let mut game = Game::new(800.0, 600.0, Difficulty::Normal, SpawnStrategy::Ring, &builtin_word_list());
+        game.words = words.iter()
+            .map(|word| Word::new(word, Point2::new(0.0, 0.0), Vector2::new(0.0, 0.0)))
+            .collect();
+        game.countdown = None;
+
+        game
+    }
+
+    #[test]
+    fn test_toggle_fullscreen() {
+        let mut game_manager = GameManager::new(Settings::default());
+        assert!(!game_manager.fullscreen);
+
+        assert_eq!(game_manager.toggle_fullscreen(false), Some(true));
+        assert_eq!(game_manager.toggle_fullscreen(true), None);
+        assert!(game_manager.fullscreen);
+
+        assert_eq!(game_manager.toggle_fullscreen(false), Some(false));
+        assert!(!game_manager.fullscreen);
+    }
+
+    #[test]
+    fn test_new_applies_settings() {
+        let settings = Settings::from_values([(THEME, THEME_HIGH_CONTRAST), (KEY_HINTS, ON), (SPAWN_PATTERN, SPAWN_EDGES)]);
+        let game_manager = GameManager::new(settings);
+
+        assert_eq!(game_manager.theme, Theme::HighContrast);
+        assert!(game_manager.show_key_hints);
+        assert_eq!(game_manager.spawn_strategy, SpawnStrategy::Edges);
+        assert_eq!(game_manager.difficulty, Difficulty::Normal);
+    }
+
+    #[test]
+    fn test_help_routing() {
+        assert_eq!(help_routing(MainMenu, KeyCode::Enter, Some(MenuAction::Help)), Some(Help));
+        assert_eq!(help_routing(MainMenu, KeyCode::Enter, Some(MenuAction::NewGame)), None);
+        assert_eq!(help_routing(MainMenu, KeyCode::Escape, Some(MenuAction::Help)), None);
+
+        assert_eq!(help_routing(Help, KeyCode::Enter, None), Some(MainMenu));
+        assert_eq!(help_routing(Help, KeyCode::Escape, None), Some(MainMenu));
+        assert_eq!(help_routing(Help, KeyCode::Up, None), None);
+
+        assert_eq!(help_routing(Paused, KeyCode::Enter, Some(MenuAction::Help)), None);
+    }
+
+    #[test]
+    fn test_exit_behavior() {
+        assert_eq!(exit_behavior(false), ExitBehavior::Quit);
+        assert_eq!(exit_behavior(true), ExitBehavior::ReturnToMenu);
+    }
+
+    #[test]
+    fn test_on_focus_lost() {
+        assert_eq!(on_focus_lost(Active), Paused);
+        assert_eq!(on_focus_lost(Paused), Paused);
+        assert_eq!(on_focus_lost(MainMenu), MainMenu);
+        assert_eq!(on_focus_lost(GameOver), GameOver);
+    }
+
+    #[test]
+    fn test_resume_skips_first_frame() {
+        let mut game_manager = GameManager { game_state: Paused, ..Default::default() };
+
+        game_manager.resume();
+
+        assert_eq!(game_manager.game_state, Active);
+        assert!(game_manager.first_frame, "the frame after resuming shouldn't count as losing focus");
+    }
+
+    #[test]
+    fn test_exit_confirmation_state() {
+        assert_eq!(exit_confirmation_state(Some(MenuAction::ConfirmExit)), None);
+        assert_eq!(exit_confirmation_state(Some(MenuAction::CancelExit)), Some(Paused));
+        assert_eq!(exit_confirmation_state(None), Some(Paused));
+    }
+
+    #[test]
+    fn test_word_velocity_by_difficulty() {
+        let position = Point2::new(100.0, 50.0);
+        let target = Point2::new(400.0, 570.0);
+
+        let magnitude = |difficulty: Difficulty| {
+            let velocity = word_velocity(position, target, 600.0, difficulty.speed());
+
+            (velocity.x * velocity.x + velocity.y * velocity.y).sqrt()
+        };
+
+        assert!(magnitude(Difficulty::Easy) < magnitude(Difficulty::Normal));
+        assert!(magnitude(Difficulty::Normal) < magnitude(Difficulty::Hard));
+    }
+
+    #[test]
+    fn test_word_list_deduplicated() {
+        let word_list: Vec<String> = ["net", "cat", "net", "dog", "cat"].iter().map(|word| word.to_string()).collect();
+
+        let game = Game::new(800.0, 600.0, Difficulty::Normal, SpawnStrategy::Ring, &word_list);
+
+        let mut pending: Vec<String> = game.pending.iter().map(Word::text).collect();
+        pending.sort();
+        assert_eq!(pending, vec!["cat", "dog", "net"]);
+    }
+
+    #[test]
+    fn test_clamp_delta() {
+        assert_eq!(clamp_delta(0.016), 0.016);
+        assert_eq!(clamp_delta(30.0), MAX_FRAME_SECS);
+        assert_eq!(clamp_delta(-1.0), 0.0);
+    }
+
+    #[test]
+    fn test_easy_word_lengths() {
+        let word_list: Vec<String> = ["cat", "giraffe", "ox", "hippopotamus", "lion", "emu", "gnu"].iter()
+            .map(|word| word.to_string())
+            .collect();
+
+        let game = Game::new(800.0, 600.0, Difficulty::Easy, SpawnStrategy::Ring, &word_list);
+        assert!(game.word_list.iter().all(|word| Difficulty::Easy.word_length_range().contains(&word.chars().count())));
+        assert_eq!(game.word_list, ["cat", "emu", "gnu"]);
+
+        let game = Game::new(800.0, 600.0, Difficulty::Hard, SpawnStrategy::Ring, &word_list);
+        assert_eq!(game.word_list.len(), 7);
+
+        let long_words: Vec<String> = vec!["hippopotamus".to_string()];
+        assert_eq!(filter_word_length(long_words.clone(), Difficulty::Easy.word_length_range()), long_words);
+    }
+
+    #[test]
+    fn test_empty_word_list_falls_back_to_builtin() {
+        let keymap = Keymap::default();
+        let builtin = typeable_words(&builtin_word_list(), &keymap);
+
+        assert!(!has_typeable_words(&[], &keymap));
+        let game = Game::new(800.0, 600.0, Difficulty::Normal, SpawnStrategy::Ring, &[]);
+        assert_eq!(game.word_list, builtin);
+
+        let untypeable: Vec<String> = vec!["éé".to_string(), "ñ".to_string()];
+        assert!(!has_typeable_words(&untypeable, &keymap));
+        assert!(has_typeable_words(&["ñ".to_string(), "cat".to_string()], &keymap));
+        let game = Game::new(800.0, 600.0, Difficulty::Normal, SpawnStrategy::Ring, &untypeable);
+        assert_eq!(game.word_list, builtin);
+        assert!(!game.pending.is_empty());
+    }
+
+    #[test]
+    fn test_untypeable_words_dropped() {
+        let word_list: Vec<String> = ["cat", "éé", "café"].iter().map(|word| word.to_string()).collect();
+
+        let game = Game::new(800.0, 600.0, Difficulty::Normal, SpawnStrategy::Ring, &word_list);
+
+        let mut pending: Vec<String> = game.pending.iter().map(Word::text).collect();
+        pending.sort();
+        assert_eq!(pending, vec!["café", "cat"]);
+    }
+
+    #[test]
+    fn test_ring_words_ignore_keypresses() {
+        let mut game = Game::new(800.0, 600.0, Difficulty::Normal, SpawnStrategy::Ring, &[]);
+        game.countdown = None;
+
+        game.step(&[(KeyCode::Key0, false)], 0.016);
+        game.step(&[(KeyCode::Key1, false), (KeyCode::Key5, false)], 0.016);
+
+        assert!(game.words.iter().all(|word| word.num_typed == 0));
+        assert_eq!(game.active_target, None);
+    }
+
+    #[test]
+    fn test_target_acquisition() {
+        let mut game = test_game(&["dog", "and", "are"]);
+
+        assert_eq!(game.active_target, None);
+
+        game.type_key(KeyCode::X, false);
+        assert_eq!(game.active_target, None);
+
+        game.type_key(KeyCode::A, false);
+        assert_eq!(game.active_target, Some(1));
+        assert_eq!(game.words[1].num_typed, 1);
+        assert_eq!(game.words[2].num_typed, 0);
+
+        game.type_key(KeyCode::R, false);
+        assert_eq!(game.active_target, Some(1));
+        assert_eq!(game.words[1].num_typed, 1);
+        assert_eq!(game.words[2].num_typed, 0);
+
+        game.type_key(KeyCode::N, false);
+        assert_eq!(game.words[1].num_typed, 2);
+    }
+
+    #[test]
+    fn test_spawn_from_edges() {
+        let words: Vec<String> = builtin_word_list().into_iter().take(30).collect();
+        let target = player_position(800.0, 600.0);
+        let mut rng = StdRng::seed_from_u64(7);
+
+        let spawned = spawn_from_edges(&words, 800.0, 600.0, target, 1.0, &mut rng);
+        assert_eq!(spawned.len(), words.len());
+
+        for word in spawned {
+            let position = word.position();
+            let velocity = word.velocity();
+
+            let on_screen = (0.0..=800.0).contains(&position.x) && (0.0..=600.0).contains(&position.y);
+            assert!(!on_screen, "{position:?} should start off screen");
+
+            let inward = velocity.x * (target.x - position.x) + velocity.y * (target.y - position.y);
+            assert!(inward > 0.0, "{velocity:?} should head for the player from {position:?}");
+        }
+
+        let game = Game::new_seeded(800.0, 600.0, Difficulty::Normal, SpawnStrategy::Edges, &words, 7);
+        assert_eq!(game.pending.len(), level_batch_size(1));
+        assert!(game.pending.iter().all(|word| is_off_screen(word.position(), 800.0, 600.0, 0.0)));
+    }
+
+    #[test]
+    fn test_one_keypress_advances_one_word() {
+        let mut game = test_game(&["ant", "ape", "axe"]);
+
+        game.step(&[(KeyCode::A, false)], 0.016);
+
+        let advanced = game.words.iter().filter(|word| word.num_typed > 0).count();
+        assert_eq!(advanced, 1);
+
+        // a second press of the same key in the same frame goes to the target, not another word
+        game.step(&[(KeyCode::P, false), (KeyCode::A, false)], 0.016);
+        let advanced = game.words.iter().filter(|word| word.num_typed > 0).count();
+        assert_eq!(advanced, 1);
+    }
+
+    #[test]
+    fn test_target_release() {
+        let mut game = test_game(&["and", "are"]);
+
+        game.type_key(KeyCode::A, false);
+        assert_eq!(game.active_target, Some(0));
+
+        game.type_key(KeyCode::Backspace, false);
+        assert_eq!(game.active_target, None);
+
+        game.type_key(KeyCode::A, false);
+        assert_eq!(game.active_target, Some(0));
+
+        game.words[0].state = WordState::Typed;
+        game.release_target();
+        assert_eq!(game.active_target, None);
+
+        game.type_key(KeyCode::A, false);
+        assert_eq!(game.active_target, Some(1));
+    }
+
+    #[test]
+    fn test_stats() {
+        let mut game = test_game(&["cat", "dog"]);
+
+        game.type_key(KeyCode::C, false);
+        game.type_key(KeyCode::X, false);
+        game.type_key(KeyCode::LeftShift, true);
+        game.type_key(KeyCode::A, false);
+
+        assert_eq!(game.stats().total_keystrokes, 3);
+        assert_eq!(game.stats().correct_keystrokes, 2);
+        assert_eq!(game.stats().mistakes, 1);
+    }
+
+    #[test]
+    fn test_spawn() {
+        let mut game = test_game(&["cat"]);
+        let num_pending = game.pending.len();
+
+        game.step(&[], (game.spawn_interval / 2.0) as f32);
+
+        assert_eq!(game.words.len(), 1);
+        assert_eq!(game.pending.len(), num_pending);
+
+        game.step(&[], (game.spawn_interval / 2.0) as f32);
+
+        assert_eq!(game.words.len(), 2);
+        assert_eq!(game.pending.len(), num_pending - 1);
+    }
+
+    #[test]
+    fn test_score() {
+        let mut game = test_game(&["cat", "be"]);
+
+        assert_eq!(game.score(), 0);
+
+        game.step(&[(KeyCode::C, false)], 0.016);
+        game.step(&[(KeyCode::A, false)], 0.016);
+
+        assert_eq!(game.score(), 0);
+
+        game.step(&[(KeyCode::T, false)], 0.016);
+
+        assert_eq!(game.score(), 30);
+    }
+
+    #[test]
+    fn test_multiple_keypresses_per_tick() {
+        let mut game = test_game(&["cat"]);
+
+        game.step(&[(KeyCode::C, false), (KeyCode::A, false)], 0.016);
+
+        assert_eq!(game.words[0].num_typed, 2);
+        assert_eq!(game.stats().correct_keystrokes, 2);
+    }
+
+    #[test]
+    fn test_on_resize() {
+        let mut game = test_game(&[]);
+        game.words = vec![Word::new("cat", Point2::new(200.0, 100.0), Vector2::new(120.0, 60.0))];
+        game.player.position = Point2::new(400.0, 570.0);
+
+        game.on_resize((800.0, 600.0), (1600.0, 300.0));
+
+        assert_eq!(game.words[0].position(), Point2::new(400.0, 50.0));
+        assert_eq!(game.player.position, Point2::new(800.0, 270.0));
+
+        game.step(&[], 0.5);
+        assert_eq!(game.words[0].position(), Point2::new(520.0, 65.0));
+    }
+
+    #[test]
+    fn test_player_move_by() {
+        let mut player = Player::with_default_precision(Point2::new(400.0, 570.0), 4.0);
+
+        player.move_by(-100.0, 800.0);
+        assert_eq!(player.position, Point2::new(300.0, 570.0));
+
+        player.move_by(-1000.0, 800.0);
+        assert_eq!(player.position.x, 4.0);
+
+        player.move_by(1000.0, 800.0);
+        assert_eq!(player.position.x, 796.0);
+
+        player.move_by(1.0, 800.0);
+        assert_eq!(player.position.x, 796.0);
+    }
+
+    #[test]
+    fn test_player_precision_clamped() {
+        let position = Point2::new(400.0, 570.0);
+
+        assert_eq!(Player::with_default_precision(position, 4.0).precision, PLAYER_PRECISION);
+        assert_eq!(Player::new(position, 4.0, 0.0).precision, 0.001);
+        assert_eq!(Player::new(position, 4.0, 10.0).precision, 0.5);
+        assert_eq!(Player::new(position, 4.0, 0.05).precision, 0.05);
+
+        assert_eq!(Player::new(position, 4.0, f32::NAN).precision, PLAYER_PRECISION);
+        assert_eq!(Player::new(position, 4.0, f32::INFINITY).precision, PLAYER_PRECISION);
+    }
+
+    #[test]
+    fn test_player_color() {
+        let mut player = Player::with_default_precision(Point2::new(400.0, 570.0), 4.0);
+        assert_eq!(player.color, ColorPalette::Orange);
+
+        player.set_color(ColorPalette::Blue);
+        assert_eq!(player.color, ColorPalette::Blue);
+    }
+
+    #[test]
+    fn test_player_visual_center() {
+        let player = Player::with_default_precision(Point2::new(400.0, 570.0), 4.0);
+
+        assert_eq!(player.visual_center(), player.position);
+    }
+
+    #[test]
+    fn test_countdown() {
+        let mut game = test_game(&[]);
+        game.words = vec![Word::new("cat", Point2::new(100.0, 100.0), Vector2::new(50.0, 50.0))];
+        game.countdown = Some(COUNTDOWN_SECS);
+
+        for _ in 0..10 {
+            game.step(&[(KeyCode::C, false)], 0.25);
+        }
+
+        assert_eq!(game.words[0].position(), Point2::new(100.0, 100.0));
+        assert_eq!(game.words[0].num_typed, 0);
+        assert_eq!(game.countdown, Some(0.5));
+
+        game.step(&[], 0.5);
+        assert_eq!(game.countdown, None);
+
+        game.step(&[], 0.25);
+        assert_eq!(game.words[0].position(), Point2::new(112.5, 112.5));
+        assert_eq!(game.elapsed_secs(), 0.25);
+    }
+
+    #[test]
+    fn test_format_clock() {
+        assert_eq!(format_clock(0.0), "00:00");
+        assert_eq!(format_clock(59.9), "00:59");
+        assert_eq!(format_clock(65.0), "01:05");
+        assert_eq!(format_clock(3599.0), "59:59");
+    }
+
+    #[test]
+    fn test_countdown_label() {
+        assert_eq!(countdown_label(COUNTDOWN_SECS), "3");
+        assert_eq!(countdown_label(2.0), "2");
+        assert_eq!(countdown_label(1.0), "1");
+        assert_eq!(countdown_label(0.5), "Go!");
+        assert_eq!(countdown_label(0.0), "Go!");
+    }
+
+    #[test]
+    fn test_level_progression() {
+        let mut game = test_game(&["cat"]);
+        game.pending.clear();
+
+        assert_eq!(game.level(), 1);
+        assert!(!game.all_words_cleared());
+
+        for key in [KeyCode::C, KeyCode::A, KeyCode::T] {
+            game.step(&[(key, false)], 0.016);
+        }
+
+        // wait out the death animation
+        for _ in 0..5 {
+            game.step(&[], 0.5);
+        }
+
+        assert_eq!(game.level(), 2);
+        assert!(game.words.iter().all(|word| word.is_decorative()));
+        assert_eq!(game.pending.len() + game.words.len(), level_batch_size(2));
+        assert!(level_speed(2) > level_speed(1));
+    }
+
+    #[test]
+    fn test_collision_costs_a_life() {
+        let mut game = test_game(&["cat", "dog"]);
+        game.pending.clear();
+
+        let player_position = game.player.position;
+        game.words[0] = Word::new("cat", player_position, Vector2::new(0.0, 0.0));
+
+        game.step(&[], 0.016);
+
+        assert_eq!(game.lives(), STARTING_LIVES - 1);
+        assert_eq!(game.words.len(), 1);
+        assert_eq!(game.words[0].text(), "dog");
+        assert!(game.hit_flash > 0.0);
+        assert!(!game.is_over());
+
+        // a destroyed word doesn't cost another life
+        game.step(&[], 0.016);
+        assert_eq!(game.lives(), STARTING_LIVES - 1);
+    }
+
+    #[test]
+    fn test_simulate() {
+        let mut game = test_game(&[]);
+        game.pending.clear();
+        game.words.push(Word::new("cat", Point2::new(100.0, 100.0), Vector2::new(60.0, 0.0)));
+
+        assert_eq!(game.simulate(&[], 0.25, 8), (0, 1));
+        assert_eq!(game.words[0].position(), Point2::new(220.0, 100.0));
+        assert_eq!(game.score(), 0);
+    }
+
+    #[test]
+    fn test_game_over_at_zero_lives() {
+        let mut game = test_game(&[]);
+        game.pending.clear();
+
+        let player_position = game.player.position;
+
+        for _ in 0..STARTING_LIVES {
+            assert!(!game.is_over());
+
+            game.words.push(Word::new("cat", player_position, Vector2::new(0.0, 0.0)));
+            game.step(&[], 0.016);
+        }
+
+        assert_eq!(game.lives(), 0);
+        assert!(game.is_over());
+    }
+
+    #[test]
+    fn test_shake_settles() {
+        let mut game = test_game(&["cat"]);
+        game.pending.clear();
+
+        let player_position = game.player.position;
+        game.words[0] = Word::new("cat", player_position, Vector2::new(0.0, 0.0));
+
+        game.step(&[], 0.016);
+        assert!(game.shake > 0.0);
+
+        for _ in 0..100 {
+            game.step(&[], 0.016);
+            assert!(game.shake >= 0.0);
+        }
+
+        assert_eq!(game.shake, 0.0);
+    }
+
+    #[test]
+    fn test_place_words_spacing() {
+        let words: Vec<String> = builtin_word_list().into_iter().take(12).collect();
+
+        let placed = place_words(
+            &words,
+            Point2::new(200.0, 120.0),
+            150.0,
+            Point2::new(200.0, 270.0),
+            1.0,
+            MIN_WORD_SPACING,
+            &mut StdRng::seed_from_u64(3),
+        );
+
+        assert_eq!(placed.len(), words.len());
+
+        // with this much room, rejection sampling shouldn't ever need to give up
+        for (i, a) in placed.iter().enumerate() {
+            for b in &placed[i + 1..] {
+                let dx = a.position().x - b.position().x;
+                let dy = a.position().y - b.position().y;
+
+                assert!((dx * dx + dy * dy).sqrt() >= MIN_WORD_SPACING);
+            }
+        }
+    }
+
+    #[test]
+    fn test_seeded_layout() {
+        let word_list = builtin_word_list();
+        let a = Game::new_seeded(800.0, 600.0, Difficulty::Normal, SpawnStrategy::Ring, &word_list, 42);
+        let b = Game::new_seeded(800.0, 600.0, Difficulty::Normal, SpawnStrategy::Ring, &word_list, 42);
+
+        let layout = |game: &Game| {
+            game.pending.iter()
+                .map(|word| (word.text(), word.position().x, word.position().y))
+                .collect::<Vec<_>>()
+        };
+
+        assert!(!a.pending.is_empty());
+        assert_eq!(layout(&a), layout(&b));
+
+        let c = Game::new_seeded(800.0, 600.0, Difficulty::Normal, SpawnStrategy::Ring, &word_list, 43);
+        assert_ne!(layout(&a), layout(&c));
+    }
+
+    #[test]
+    fn test_key_queue_in_order() {
+        let mut game = test_game(&["at", "ta"]);
+
+        game.key_queue.extend([(KeyCode::A, false), (KeyCode::T, false)]);
+        game.step_queued(0.016);
+
+        assert!(game.key_queue.is_empty());
+        assert_eq!(game.words[0].state, WordState::Typed);
+        assert_eq!(game.words[1].num_typed, 0);
+    }
+
+    #[test]
+    fn test_same_key_twice_in_one_frame() {
+        let mut game = test_game(&["egg"]);
+
+        for key in [KeyCode::E, KeyCode::G, KeyCode::G] {
+            game.buffer_key(key, false, false);
+        }
+        game.step_queued(0.016);
+
+        assert_eq!(game.words[0].state, WordState::Typed);
+
+        let mut game = test_game(&["egg"]);
+
+        game.buffer_key(KeyCode::E, false, false);
+        game.buffer_key(KeyCode::G, false, false);
+        game.buffer_key(KeyCode::G, false, true);
+        game.step_queued(0.016);
+
+        assert_eq!(game.words[0].num_typed, 2, "a held key's repeats shouldn't count");
+    }
+
+    #[test]
+    fn test_shift_is_per_key() {
+        let mut game = test_game(&["Go"]).with_case_sensitive(true);
+
+        game.buffer_key(KeyCode::G, true, false);
+        game.buffer_key(KeyCode::O, false, false);
+        game.step_queued(0.016);
+
+        assert_eq!(game.words[0].num_typed, 2);
+    }
+
+    #[test]
+    fn test_is_off_screen() {
+        assert!(!is_off_screen(Point2::new(400.0, 300.0), 800.0, 600.0, 100.0));
+        assert!(!is_off_screen(Point2::new(850.0, -50.0), 800.0, 600.0, 100.0));
+        assert!(is_off_screen(Point2::new(901.0, 300.0), 800.0, 600.0, 100.0));
+        assert!(is_off_screen(Point2::new(400.0, 701.0), 800.0, 600.0, 100.0));
+        assert!(is_off_screen(Point2::new(-5000.0, -5000.0), 800.0, 600.0, 100.0));
+    }
+
+    #[test]
+    fn test_cull_off_screen() {
+        let mut game = Game::new(800.0, 600.0, Difficulty::Normal, SpawnStrategy::Ring, &[]);
+        game.countdown = None;
+        game.player.position = Point2::new(400.0, 570.0);
+
+        let incoming = Word::new("cat", Point2::new(400.0, -300.0), Vector2::new(0.0, 1.0));
+        let receding = Word::new("dog", Point2::new(400.0, 750.0), Vector2::new(0.0, 1.0));
+        game.words = vec![incoming, receding];
+
+        game.step(&[], 0.016);
+
+        assert_eq!(game.words.len(), 1);
+        assert_eq!(game.words[0].text(), "cat");
+        assert_eq!(game.lives(), STARTING_LIVES);
+    }
+
+    #[test]
+    fn test_dead_words_removed() {
+        let mut game = test_game(&["cat", "dog", "emu"]);
+        game.pending.clear();
+
+        for key in [KeyCode::C, KeyCode::A, KeyCode::T] {
+            game.step(&[(key, false)], 0.016);
+        }
+
+        game.step(&[(KeyCode::E, false)], 0.016);
+        assert_eq!(game.active_target, Some(2));
+
+        // wait out the death animation
+        for _ in 0..100 {
+            game.step(&[], 0.016);
+        }
+
+        assert_eq!(game.words.len(), 2);
+        assert_eq!(game.active_target, Some(1));
+        assert_eq!(game.words[1].text(), "emu");
+        assert_eq!(game.words[1].num_typed, 1);
+    }
+
+    #[test]
+    fn test_combo_multiplier() {
+        assert_eq!(combo_multiplier(0), 1);
+        assert_eq!(combo_multiplier(9), 1);
+        assert_eq!(combo_multiplier(10), 2);
+        assert_eq!(combo_multiplier(25), 3);
+    }
+
+    #[test]
+    fn test_combo() {
+        let mut game = test_game(&["cat", "dog"]);
+        game.pending.clear();
+        game.combo = 9;
+
+        for key in [KeyCode::C, KeyCode::A, KeyCode::T] {
+            game.step(&[(key, false)], 0.016);
+        }
+
+        assert_eq!(game.combo, 10);
+        assert_eq!(game.score(), 60);
+
+        // a stray key with no target isn't a mistake
+        game.step(&[(KeyCode::X, false)], 0.016);
+        assert_eq!(game.combo, 10);
+
+        game.step(&[(KeyCode::D, false)], 0.016);
+        game.step(&[(KeyCode::X, false)], 0.016);
+        assert_eq!(game.combo, 0);
+    }
+
+    #[test]
+    fn test_mistakes() {
+        let mut game = test_game(&["cat"]);
+        game.pending.clear();
+
+        // with no word targeted, a key matching nothing isn't counted
+        game.step(&[(KeyCode::X, false)], 0.016);
+        assert_eq!(game.mistakes(), 0);
+        assert_eq!(game.mistake_flash, 0.0);
+
+        game.step(&[(KeyCode::C, false)], 0.016);
+        game.step(&[(KeyCode::X, false)], 0.016);
+        assert_eq!(game.mistakes(), 1);
+        assert!(game.mistake_flash > 0.0);
+
+        // backspacing to the start releases the target, so stray keys stop counting again
+        game.step(&[(KeyCode::Backspace, false)], 0.016);
+        game.step(&[(KeyCode::Z, false)], 0.016);
+        assert_eq!(game.mistakes(), 1);
+    }
+
+    #[test]
+    fn test_key_after_completion() {
+        let mut game = test_game(&["cat", "dog"]);
+        game.pending.clear();
+
+        game.step(&[(KeyCode::C, false), (KeyCode::A, false), (KeyCode::T, false)], 0.016);
+        assert_eq!(game.words[0].state, WordState::Typed);
+
+        game.step(&[(KeyCode::D, false)], 0.016);
+        assert_eq!(game.words[1].num_typed, 1);
+
+        game.step(&[(KeyCode::O, false)], 0.016);
+        assert_eq!(game.words[1].num_typed, 2);
+    }
+
+    #[test]
+    fn test_backspace_without_target() {
+        let mut game = test_game(&["cat", "dog"]);
+        game.pending.clear();
+
+        game.step(
+            &[
+                (KeyCode::C, false), (KeyCode::A, false), (KeyCode::T, false),
+                (KeyCode::D, false), (KeyCode::O, false), (KeyCode::G, false),
+                (KeyCode::Backspace, false),
+            ],
+            0.016,
+        );
+
+        assert_eq!(game.words[0].num_typed, 3);
+        assert_eq!(game.words[1].num_typed, 3);
+        assert_eq!(game.words[0].state, WordState::Typed);
+        assert_eq!(game.words[1].state, WordState::Typed);
+        assert!(game.score() > 0);
+    }
+
+    #[test]
+    fn test_two_words_in_one_step() {
+        let mut game = test_game(&["cat", "dog"]);
+        game.pending.clear();
+
+        game.step(&[(KeyCode::C, false), (KeyCode::A, false), (KeyCode::T, false), (KeyCode::D, false)], 0.016);
+
+        assert_eq!(game.mistakes(), 0);
+        assert_eq!(game.combo, 1);
+        assert_eq!(game.words[0].num_typed, 3);
+        assert_eq!(game.words[1].num_typed, 1);
+    }
+
+    #[test]
+    fn test_freeze() {
+        let mut game = test_game(&["cat", "dog"]);
+        game.pending.clear();
+        game.words[1] = Word::new("dog", Point2::new(100.0, 100.0), Vector2::new(40.0, 0.0));
+        game.combo = FREEZE_COMBO - 1;
+
+        for key in [KeyCode::C, KeyCode::A, KeyCode::T] {
+            game.step(&[(key, false)], 0.016);
+        }
+        assert!(game.freeze_timer > 0.0);
+
+        let dog = |game: &Game| game.words.iter().find(|word| word.text() == "dog").unwrap().position();
+        let frozen_at = dog(&game);
+
+        for _ in 0..10 {
+            game.step(&[], 0.25);
+        }
+        assert_eq!(dog(&game), frozen_at);
+
+        game.step(&[], 0.5);
+        game.step(&[], 0.25);
+        assert_eq!(game.freeze_timer, 0.0);
+        assert_eq!(dog(&game).x, frozen_at.x + 10.0);
+    }
+
+    #[test]
+    fn test_time_scale() {
+        let displacement = |time_scale: f32| {
+            let mut game = test_game(&["cat"]).with_time_scale(time_scale);
+            game.pending.clear();
+            game.words[0] = Word::new("cat", Point2::new(100.0, 100.0), Vector2::new(120.0, 0.0));
+
+            // one simulated second
+            for _ in 0..60 {
+                game.step(&[], 1.0 / 60.0);
+            }
+
+            game.words[0].position().x - 100.0
+        };
+
+        assert!((displacement(1.0) - 120.0).abs() < 0.01);
+        assert!((displacement(0.5) - 60.0).abs() < 0.01);
+        assert_eq!(time_scale_from_menu_item(SPEED_SLOWER), Some(0.5));
+    }
+
+    #[test]
+    fn test_trajectory_end() {
+        let position = Point2::new(100.0, 50.0);
+
+        assert_eq!(trajectory_end(position, Vector2::new(0.0, 0.0)), position);
+        assert_eq!(trajectory_end(position, Vector2::new(60.0, 120.0)), Point2::new(160.0, 170.0));
+        assert_eq!(trajectory_end(position, Vector2::new(-30.0, 0.0)), Point2::new(70.0, 50.0));
+    }
+
+    #[test]
+    fn test_sound_queue() {
+        let mut game = test_game(&["at"]);
+
+        game.step(&[(KeyCode::X, false)], 0.016);
+        assert!(game.sound_queue.is_empty());
+
+        game.step(&[(KeyCode::A, false)], 0.016);
+        game.step(&[(KeyCode::T, false)], 0.016);
+
+        assert_eq!(game.sound_queue, vec![SoundEffect::Keypress, SoundEffect::Keypress, SoundEffect::WordComplete]);
+    }
+
+    #[test]
+    fn test_particles() {
+        let mut game = test_game(&["at"]);
+
+        game.step(&[(KeyCode::A, false)], 0.016);
+        assert!(game.particles.is_empty());
+
+        game.step(&[(KeyCode::T, false)], 0.016);
+        assert_eq!(game.particles.len(), PARTICLES_PER_BURST);
+
+        game.step(&[], 1.0);
+        assert!(game.particles.is_empty());
     }
 }
-const WORD_LIST: [&str; 171] = [
-    "and",		
-    "are",		
-    "ape",		
-    "ace",		
-    "act",		
-    "ask",		
-    "arm",		
-    "age",		
-    "ago",		
-    "air",		
-    "ate",		
-    "all",		
-    "but",		
-    "bye",		
-    "bad",		
-    "big",		
-    "bed",		
-    "bat",		
-    "boy",		
-    "bus",		
-    "bag",		
-    "box",		
-    "bit",		
-    "bee",		
-    "buy",		
-    "bun",		
-    "cub",		
-    "cat",		
-    "car",		
-    "cut",		
-    "cow",		
-    "cry",		
-    "cab",		
-    "can",		
-    "dad",		
-    "dab",		
-    "dam",		
-    "did",		
-    "dug",		
-    "den",		
-    "dot",		
-    "dip",		
-    "day",		
-    "ear",		
-    "eye",		
-    "eat",		
-    "end",		
-    "elf",		
-    "egg",		
-    "far",		
-    "fat",		
-    "few",		
-    "fan",		
-    "fun",		
-    "fit",		
-    "fin",		
-    "fox",		
-    "fix",
-    "fly",
-    "fry",
-    "for",
-    "got",
-    "get",
-    "god",
-    "gel",
-    "gas",
-    "hat",
-    "hit",
-    "has",
-    "had",
-    "how",
-    "her",
-    "his",
-    "hen",
-    "ink",
-    "ice",
-    "ill",
-    "jab",
-    "jug",
-    "jet",
-    "jam",
-    "jar",
-    "job",
-    "jog",
-    "kit",
-    "key",
-    "lot",
-    "lit",
-    "let",
-    "lay",
-    "mat",
-    "man",
-    "mad",
-    "mug",
-    "mix",
-    "map",
-    "mum",
-    "mud",
-    "mom",
-    "may",
-    "met",
-    "net",
-    "new",
-    "nap",
-    "now",
-    "nod",
-    "net",
-    "not",
-    "nut",
-    "oar",
-    "one",
-    "out",
-    "owl",
-    "old",
-    "own",
-    "odd",
-    "our",
-    "pet",
-    "pat",
-    "peg",
-    "paw",
-    "pup",
-    "pit",
-    "put",
-    "pot",
-    "pop",
-    "pin",
-    "rat",
-    "rag",
-    "rub",
-    "row",
-    "rug",
-    "run",
-    "rap",
-    "ram",
-    "sow",
-    "see",
-    "saw",
-    "set",
-    "sit",
-    "sir",
-    "sat",
-    "sob",
-    "tap",
-    "tip",
-    "top",
-    "tug",
-    "tow",
-    "toe",
-    "tan",
-    "ten",
-    "two",
-    "use",
-    "van",
-    "vet",
-    "was",
-    "wet",
-    "win",
-    "won",
-    "wig",
-    "war",
-    "why",
-    "who",
-    "way",
-    "wow",
-    "you",
-    "yes",
-    "yak",
-    "yet",
-    "zip",
-    "zap",
-];
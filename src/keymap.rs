@@ -0,0 +1,155 @@
+use std::collections::HashMap;
+
+use good_web_game::input::keyboard::KeyCode;
+
+use crate::word::{ch_to_keycode, keycode_to_ch, numpad_keycode};
+
+// The characters printed on each row of keys, left to right, under each layout.
+const QWERTY_ROWS: [&str; 4] = ["1234567890-=", "qwertyuiop[]", "asdfghjkl;'", "zxcvbnm,./"];
+const DVORAK_ROWS: [&str; 4] = ["1234567890[]", "',.pyfgcrl/=", "aoeuidhtns-", ";qjkxbmwvz"];
+
+/// The keyboard layouts a `Keymap` can be built for.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Layout {
+    Qwerty,
+    Dvorak,
+}
+
+/// Which physical key types each character, for a particular keyboard layout.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Keymap {
+    layout: Layout,
+    keys: HashMap<char, KeyCode>,
+}
+
+impl Keymap {
+    pub fn qwerty() -> Self {
+        Self {
+            layout: Layout::Qwerty,
+            keys: (' '..='~')
+                .filter_map(|ch| ch_to_keycode(ch).map(|key_code| (ch, key_code)))
+                .collect(),
+        }
+    }
+
+    pub fn dvorak() -> Self {
+        let mut keys: HashMap<char, KeyCode> = QWERTY_ROWS.iter()
+            .zip(DVORAK_ROWS.iter())
+            .flat_map(|(qwerty, dvorak)| qwerty.chars().zip(dvorak.chars()))
+            // only remap characters the game can type at all
+            .filter(|(_, dvorak_ch)| ch_to_keycode(*dvorak_ch).is_some())
+            .filter_map(|(qwerty_ch, dvorak_ch)| physical_key(qwerty_ch).map(|key_code| (dvorak_ch, key_code)))
+            .collect();
+
+        keys.insert(' ', KeyCode::Space);
+
+        Self { layout: Layout::Dvorak, keys }
+    }
+
+    pub fn layout(&self) -> Layout {
+        self.layout
+    }
+
+    /// The key that types `ch`, and whether shift must be held.
+    pub fn key_for(&self, ch: char) -> Option<(KeyCode, bool)> {
+        if ch.is_ascii_uppercase() {
+            return self.keys.get(&ch.to_ascii_lowercase()).map(|key_code| (*key_code, true));
+        }
+
+        self.keys.get(&ch).map(|key_code| (*key_code, false))
+    }
+
+    /// The QWERTY keycaps to press to type `word` under this layout, capitalized where shift is
+    /// needed, with `?` for characters this layout can't type.
+    pub fn key_hint(&self, word: &str) -> String {
+        word.chars()
+            .map(|ch| {
+                self.key_for(ch)
+                    .and_then(|(key_code, shift)| {
+                        key_label(key_code).map(|label| if shift { label.to_ascii_uppercase() } else { label })
+                    })
+                    .unwrap_or('?')
+            })
+            .collect()
+    }
+
+    /// Whether the key types some character, as opposed to e.g. a modifier or navigation key.
+    pub fn is_typing_key(&self, key_code: KeyCode) -> bool {
+        self.keys.iter().any(|(ch, code)| *code == key_code || numpad_keycode(*ch) == Some(key_code))
+    }
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        Self::qwerty()
+    }
+}
+
+/// The key at the position of `ch` on a QWERTY keyboard, including keys the game never types.
+fn physical_key(ch: char) -> Option<KeyCode> {
+    match ch {
+        '=' => Some(KeyCode::Equal),
+        '[' => Some(KeyCode::LeftBracket),
+        ']' => Some(KeyCode::RightBracket),
+        '/' => Some(KeyCode::Slash),
+        _ => ch_to_keycode(ch),
+    }
+}
+
+/// What's printed on a key on a QWERTY keyboard; the inverse of `physical_key`.
+fn key_label(key_code: KeyCode) -> Option<char> {
+    match key_code {
+        KeyCode::Equal => Some('='),
+        KeyCode::LeftBracket => Some('['),
+        KeyCode::RightBracket => Some(']'),
+        KeyCode::Slash => Some('/'),
+        _ => keycode_to_ch(key_code),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn required_keys(keymap: &Keymap, word: &str) -> Vec<KeyCode> {
+        word.chars().map(|ch| keymap.key_for(ch).unwrap().0).collect()
+    }
+
+    #[test]
+    fn test_qwerty_matches_ch_to_keycode() {
+        let qwerty = Keymap::qwerty();
+
+        for ch in ' '..='~' {
+            assert_eq!(qwerty.keys.get(&ch).copied(), ch_to_keycode(ch));
+        }
+    }
+
+    #[test]
+    fn test_layouts() {
+        let qwerty = Keymap::qwerty();
+        let dvorak = Keymap::dvorak();
+
+        assert_eq!(required_keys(&qwerty, "hello"), vec![KeyCode::H, KeyCode::E, KeyCode::L, KeyCode::L, KeyCode::O]);
+        assert_eq!(required_keys(&dvorak, "hello"), vec![KeyCode::J, KeyCode::D, KeyCode::P, KeyCode::P, KeyCode::S]);
+
+        assert_eq!(dvorak.key_for('Z'), Some((KeyCode::Slash, true)));
+        assert_eq!(dvorak.key_for(' '), Some((KeyCode::Space, false)));
+
+        // both layouts can type the same characters
+        for ch in ' '..='~' {
+            assert_eq!(qwerty.key_for(ch).is_some(), dvorak.key_for(ch).is_some(), "{ch:?}");
+        }
+    }
+
+    #[test]
+    fn test_key_hint() {
+        let qwerty = Keymap::qwerty();
+        let dvorak = Keymap::dvorak();
+
+        assert_eq!(qwerty.key_hint("hello"), "hello");
+        assert_eq!(dvorak.key_hint("hello"), "jdpps");
+        assert_eq!(dvorak.key_hint("Zap"), "/ar");
+        assert_eq!(dvorak.key_hint("a z"), "a /");
+        assert_eq!(qwerty.key_hint("café"), "caf?");
+    }
+}
@@ -0,0 +1,149 @@
+use crate::{read_data_file, write_data_file};
+
+const LEADERBOARD_FILE: &str = "leaderboard.txt";
+/// How many scores the leaderboard keeps.
+pub const LEADERBOARD_SIZE: usize = 10;
+
+/// The best scores so far and who set them, highest first, saved between sessions as
+/// `score name` lines.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Leaderboard {
+    entries: Vec<(String, usize)>,
+}
+
+impl Leaderboard {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn entries(&self) -> &[(String, usize)] {
+        &self.entries
+    }
+
+    /// Whether `score` would make it onto the board.
+    pub fn qualifies(&self, score: usize) -> bool {
+        score > 0 && self.rank(score) < LEADERBOARD_SIZE
+    }
+
+    /// Adds `name`'s `score`, returning its place from 0 if it made the board. Ties go below the
+    /// scores already there, since those were set first.
+    pub fn insert(&mut self, name: &str, score: usize) -> Option<usize> {
+        let rank = self.rank(score);
+
+        if rank >= LEADERBOARD_SIZE {
+            return None;
+        }
+
+        self.entries.insert(rank, (name.to_string(), score));
+        self.entries.truncate(LEADERBOARD_SIZE);
+
+        Some(rank)
+    }
+
+    fn rank(&self, score: usize) -> usize {
+        self.entries.iter()
+            .position(|(_, existing)| score > *existing)
+            .unwrap_or(self.entries.len())
+    }
+
+    /// Reads the saved leaderboard, starting afresh if it is missing or malformed.
+    pub fn load() -> Self {
+        match read_data_file(LEADERBOARD_FILE) {
+            Some(contents) => parse_leaderboard(&contents).unwrap_or_else(|e| {
+                eprintln!("warning: ignoring {LEADERBOARD_FILE}: {e}");
+                Leaderboard::default()
+            }),
+            None => Leaderboard::default(),
+        }
+    }
+
+    pub fn save(&self) {
+        write_data_file(LEADERBOARD_FILE, &self.serialize());
+    }
+
+    fn serialize(&self) -> String {
+        self.entries.iter()
+            .map(|(name, score)| format!("{score} {name}\n"))
+            .collect()
+    }
+}
+
+fn parse_leaderboard(contents: &str) -> Result<Leaderboard, String> {
+    let mut leaderboard = Leaderboard::new();
+
+    for (i, line) in contents.lines().enumerate() {
+        let line = line.trim();
+
+        if line.is_empty() {
+            continue;
+        }
+
+        let (score, name) = line.split_once(' ')
+            .and_then(|(score, name)| Some((score.parse().ok()?, name.trim())))
+            .ok_or_else(|| format!("line {}: expected `score name`", i + 1))?;
+
+        leaderboard.insert(name, score);
+    }
+
+    Ok(leaderboard)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_insert_ordering() {
+        let mut leaderboard = Leaderboard::new();
+
+        assert_eq!(leaderboard.insert("bea", 200), Some(0));
+        assert_eq!(leaderboard.insert("al", 100), Some(1));
+        assert_eq!(leaderboard.insert("cy", 300), Some(0));
+
+        let scores: Vec<usize> = leaderboard.entries().iter().map(|(_, score)| *score).collect();
+        assert_eq!(scores, vec![300, 200, 100]);
+    }
+
+    #[test]
+    fn test_insert_truncates() {
+        let mut leaderboard = Leaderboard::new();
+
+        for score in 1..=LEADERBOARD_SIZE {
+            leaderboard.insert("player", score * 10);
+        }
+        assert_eq!(leaderboard.entries().len(), LEADERBOARD_SIZE);
+
+        assert!(!leaderboard.qualifies(5));
+        assert_eq!(leaderboard.insert("low", 5), None);
+
+        assert!(leaderboard.qualifies(15));
+        assert_eq!(leaderboard.insert("mid", 15), Some(LEADERBOARD_SIZE - 1));
+        assert_eq!(leaderboard.entries().len(), LEADERBOARD_SIZE);
+        assert_eq!(leaderboard.entries().last(), Some(&("mid".to_string(), 15)));
+    }
+
+    #[test]
+    fn test_ties_go_below() {
+        let mut leaderboard = Leaderboard::new();
+
+        leaderboard.insert("first", 100);
+        assert_eq!(leaderboard.insert("second", 100), Some(1));
+        assert_eq!(leaderboard.entries()[0].0, "first");
+
+        for _ in 2..LEADERBOARD_SIZE {
+            leaderboard.insert("filler", 100);
+        }
+        assert!(!leaderboard.qualifies(100), "a tie with the lowest score doesn't bump it off");
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let mut leaderboard = Leaderboard::new();
+        leaderboard.insert("Ada L", 420);
+        leaderboard.insert("bob", 90);
+
+        assert_eq!(leaderboard.serialize(), "420 Ada L\n90 bob\n");
+        assert_eq!(parse_leaderboard(&leaderboard.serialize()), Ok(leaderboard));
+        assert!(parse_leaderboard("lots bob").is_err());
+    }
+}
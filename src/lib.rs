@@ -0,0 +1,78 @@
+extern crate good_web_game as ggez;
+
+use std::env;
+use std::fs;
+use std::path;
+
+use getrandom::register_custom_getrandom;
+
+mod audio;
+pub mod color_scheme;
+pub mod config;
+mod daily;
+pub mod fonts;
+pub mod game;
+mod keymap;
+mod leaderboard;
+pub mod word;
+mod words;
+pub mod menu;
+mod particle;
+mod rolling_average;
+mod score;
+mod screen;
+pub mod settings;
+mod stats;
+mod text_cache;
+mod ui;
+
+use color_scheme::{ColorPalette, TweenableColor};
+
+fn fallback_getrandom(_buf: &mut [u8]) -> Result<(), getrandom::Error> {
+    Ok(())
+}
+
+register_custom_getrandom!(fallback_getrandom);
+
+
+/// We add the CARGO_MANIFEST_DIR/resources to the resource paths
+/// so that ggez will look in our cargo project directory for files.
+pub fn resource_dir() -> path::PathBuf {
+    resolve_resource_dir(env::var("CARGO_MANIFEST_DIR").ok())
+}
+
+/// The `resources` directory under `manifest`, or under the working directory without one.
+pub fn resolve_resource_dir(manifest: Option<String>) -> path::PathBuf {
+    match manifest {
+        Some(manifest_dir) => path::PathBuf::from(manifest_dir).join("resources"),
+        None => path::PathBuf::from("./resources"),
+    }
+}
+
+/// Contents of the file `name` saved in the resource directory, if it can be read.
+pub(crate) fn read_data_file(name: &str) -> Option<String> {
+    fs::read_to_string(resource_dir().join(name)).ok()
+}
+
+/// Saves `contents` as the file `name` in the resource directory.
+pub(crate) fn write_data_file(name: &str, contents: &str) {
+    let dir = resource_dir();
+
+    // Persistence is best-effort, e.g. there is no filesystem to write to on wasm.
+    let _ = fs::create_dir_all(&dir);
+    let _ = fs::write(dir.join(name), contents);
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_resolve_resource_dir() {
+        assert_eq!(
+            resolve_resource_dir(Some("/home/player/animated-memory".to_string())),
+            path::PathBuf::from("/home/player/animated-memory/resources"),
+        );
+        assert_eq!(resolve_resource_dir(None), path::PathBuf::from("./resources"));
+    }
+}
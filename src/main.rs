@@ -1,49 +1,34 @@
 extern crate good_web_game as ggez;
 
-use std::env;
-use std::path;
+use animated_memory::{config, fonts, game::GameManager, resource_dir, settings::Settings};
+use ggez::GameResult;
 
-use game::GameManager;
-use getrandom::register_custom_getrandom;
-use good_web_game::GameResult;
-
-mod color_scheme;
-mod game;
-mod word;
-mod menu;
-mod screen;
-
-use color_scheme::{ColorPalette, TweenableColor};
-use screen::{SCREEN_WIDTH, SCREEN_HEIGHT};
-
-fn fallback_getrandom(_buf: &mut [u8]) -> Result<(), getrandom::Error> {
-    Ok(())
-}
+fn main() -> GameResult {
+    let resource_dir = resource_dir();
 
-register_custom_getrandom!(fallback_getrandom);
+    // On wasm assets are fetched relative to the page, so there's no directory to check.
+    if !cfg!(target_arch = "wasm32") && !resource_dir.is_dir() {
+        eprintln!(
+            "warning: resource directory {} doesn't exist, fonts, sounds and words may fail to load",
+            resource_dir.display(),
+        );
+    }
 
+    let config = config::load();
 
-fn main() -> GameResult {
-    // We add the CARGO_MANIFEST_DIR/resources to the resource paths
-    // so that ggez will look in our cargo project directory for files.
-    let resource_dir = if let Ok(manifest_dir) = env::var("CARGO_MANIFEST_DIR") {
-        let mut path = path::PathBuf::from(manifest_dir);
-        path.push("resources");
-        path
-    } else {
-        path::PathBuf::from("./resources")
-    };
-
-    let conf = ggez::conf::Conf::default()
-        .window_width(SCREEN_WIDTH)
-        .window_height(SCREEN_HEIGHT)
+    let conf = config.conf()
+        .window_resizable(true)
         .physical_root_dir(Some(resource_dir));
 
-    let game_manager = GameManager::new();
+    let mut game_manager = GameManager::new(Settings::load());
+
+    if let Some(difficulty) = config.difficulty {
+        game_manager = game_manager.with_difficulty(difficulty);
+    }
 
     ggez::start(
         conf,
-        |mut _ctx, mut _gctx| Box::new(game_manager),
+        |ctx, _gctx| Box::new(game_manager.with_font(fonts::load_font(ctx))),
     )
 
 }
@@ -1,43 +1,200 @@
 use ggez::{
-    event::{self, EventHandler, KeyCode}, graphics::{self, DrawMode, Point2, Rect, Text, TextFragment}
+    event::{self, EventHandler, KeyCode, MouseButton}, graphics::{self, Color, DrawMode, Font, Mesh, Point2, Rect, Text, TextFragment},
+    timer,
 };
+use keyframe::{ease, functions::{EaseInOut, EaseOut, Linear}, AnimationSequence, Keyframe};
 
 pub const MAIN_MENU_TITLE: &str = "Animated Memory";
 pub const PAUSE_MENU_TITLE: &str = "Paused";
+pub const GAME_OVER_MENU_TITLE: &str = "Game Over";
+pub const DIFFICULTY_MENU_TITLE: &str = "Difficulty";
+pub const SETTINGS_MENU_TITLE: &str = "Settings";
+pub const WORD_PACK_MENU_TITLE: &str = "Word Pack";
+pub const EXIT_CONFIRM_MENU_TITLE: &str = "Are you sure?";
+pub const HELP_TITLE: &str = "Help";
+pub const LEADERBOARD_TITLE: &str = "Leaderboard";
 
 pub const NEW_GAME: &str = "New Game";
+pub const DAILY_CHALLENGE: &str = "Daily Challenge";
 pub const RESUME: &str = "Resume";
+pub const RESTART: &str = "Restart";
 pub const MAIN_MENU: &str = "Main Menu";
+pub const SETTINGS: &str = "Settings";
+pub const HELP: &str = "Help";
+pub const LEADERBOARD: &str = "Leaderboard";
 pub const EXIT: &str = "Exit";
+pub const BACK_TO_MENU: &str = "Back to Menu";
+
+pub const PAUSE_MENU_ITEMS: [(&str, MenuAction); 4] = [
+    (RESUME, MenuAction::Resume),
+    (RESTART, MenuAction::Restart),
+    (MAIN_MENU, MenuAction::MainMenu),
+    (EXIT, MenuAction::Exit),
+];
+
+pub const YES: &str = "Yes";
+pub const NO: &str = "No";
+
+pub const EXIT_CONFIRM_MENU_ITEMS: [(&str, MenuAction); 2] = [
+    (YES, MenuAction::ConfirmExit),
+    (NO, MenuAction::CancelExit),
+];
+
+pub const EASY: &str = "Easy";
+pub const NORMAL: &str = "Normal";
+pub const HARD: &str = "Hard";
+
+pub const THREE_LETTER: &str = "Three Letter";
+pub const ANIMALS: &str = "Animals";
+pub const COLORS: &str = "Colors";
+pub const PROGRAMMING: &str = "Programming";
+pub const CUSTOM: &str = "Custom";
+
+pub const DIFFICULTY: &str = "Difficulty";
+pub const VOLUME: &str = "Volume";
+pub const THEME: &str = "Theme";
+pub const LAYOUT: &str = "Layout";
+pub const GAME_SPEED: &str = "Game Speed";
+pub const TRAJECTORIES: &str = "Trajectories";
+pub const KEY_HINTS: &str = "Key Hints";
+pub const CASE_SENSITIVE: &str = "Case Sensitive";
+pub const HIGH_VISIBILITY: &str = "High Visibility";
+pub const PLAYER_COLOR: &str = "Player Color";
+pub const SPAWN_PATTERN: &str = "Spawn Pattern";
+
+pub const VOLUME_OFF: &str = "Off";
+pub const VOLUME_25: &str = "25%";
+pub const VOLUME_50: &str = "50%";
+pub const VOLUME_75: &str = "75%";
+pub const VOLUME_100: &str = "100%";
+
+pub const THEME_DEFAULT: &str = "Default";
+pub const THEME_DEUTERANOPIA: &str = "Deuteranopia";
+pub const THEME_HIGH_CONTRAST: &str = "High Contrast";
+
+pub const LAYOUT_QWERTY: &str = "QWERTY";
+pub const LAYOUT_DVORAK: &str = "Dvorak";
+
+pub const OFF: &str = "Off";
+pub const ON: &str = "On";
+
+pub const SPEED_NORMAL: &str = "Normal";
+pub const SPEED_SLOW: &str = "Slow";
+pub const SPEED_SLOWER: &str = "Slower";
+
+pub const PLAYER_ORANGE: &str = "Orange";
+pub const PLAYER_YELLOW: &str = "Yellow";
+pub const PLAYER_GREEN: &str = "Green";
+pub const PLAYER_BLUE: &str = "Blue";
+
+pub const SPAWN_RING: &str = "Ring";
+pub const SPAWN_EDGES: &str = "Edges";
+
+/// Opacity of the shade drawn over the game behind in-game menus.
+pub const SHADE_ALPHA: u8 = 230;
 
 const V_PADDING: f32 = 35.0;
-
-use crate::color_scheme::ColorPalette;
+/// Space between one item's box and the next item's.
+const ITEM_PADDING: f32 = V_PADDING / 2.0;
+const DEFAULT_MAX_VISIBLE: usize = 6;
+
+use crate::{color_scheme::{ColorPalette, Theme, TweenableColor}, fonts::scaled, game::Difficulty, text_cache::TextCache, words::WordPack};
+
+/// What activating a menu item does.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum MenuAction {
+    NewGame,
+    DailyChallenge,
+    Resume,
+    Restart,
+    Settings,
+    Help,
+    Leaderboard,
+    MainMenu,
+    SelectDifficulty(Difficulty),
+    SelectWordPack(WordPack),
+    Exit,
+    ConfirmExit,
+    CancelExit,
+}
 
 pub struct MainMenu<'a> {
     menu: Menu<'a>,
     show_resume: bool,
+    high_score: usize,
+    /// Loops from 0.0 to 1.0 and back, for how far the title is tinted toward its highlight.
+    title_pulse: AnimationSequence<f32>,
+}
+
+/// Seconds the title takes to brighten and fade back.
+const TITLE_PULSE_SECS: f64 = 3.0;
+/// How far toward the highlight color the title gets at the peak of its pulse.
+const TITLE_PULSE_STRENGTH: f32 = 0.35;
+
+fn title_pulse() -> AnimationSequence<f32> {
+    let mut animation = AnimationSequence::new();
+    let _ = animation.insert(Keyframe::new(0.0, 0.0, EaseInOut));
+    let _ = animation.insert(Keyframe::new(1.0, TITLE_PULSE_SECS / 2.0, EaseInOut));
+    let _ = animation.insert(Keyframe::new(0.0, TITLE_PULSE_SECS, EaseInOut));
+
+    animation
+}
+
+impl<'a> Default for MainMenu<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl<'a> MainMenu<'a> {
     pub fn new() -> Self {
+        let menu_items = [
+            (NEW_GAME, MenuAction::NewGame),
+            (DAILY_CHALLENGE, MenuAction::DailyChallenge),
+            (LEADERBOARD, MenuAction::Leaderboard),
+            (SETTINGS, MenuAction::Settings),
+            (HELP, MenuAction::Help),
+            (exit_label(), MenuAction::Exit),
+        ];
+
         Self {
-            menu: Menu::new(MAIN_MENU_TITLE, &[NEW_GAME, EXIT])
+            // only the window's height limits how many items show, Resume included
+            menu: Menu::new(MAIN_MENU_TITLE, &menu_items)
+                .max_visible(menu_items.len() + 1)
                 .shade_menu_items(true),
             show_resume: false,
+            high_score: 0,
+            title_pulse: title_pulse(),
         }
     }
 
-    pub fn selected_item(&self) -> &str {
-        self.menu.selected_item()
+    pub fn item_at(&self, x: f32, y: f32) -> Option<usize> {
+        self.menu.item_at(x, y)
+    }
+
+    pub fn set_high_score(&mut self, high_score: usize) {
+        self.high_score = high_score;
+    }
+
+    pub fn selected_action(&self) -> Option<MenuAction> {
+        self.menu.selected_action()
+    }
+
+    pub fn set_font(&mut self, font: Font) {
+        self.menu.set_font(font);
+    }
+
+    pub fn set_theme(&mut self, theme: Theme) {
+        self.menu.set_theme(theme);
     }
 
     pub fn show_resume(&mut self, show: bool) {
         if show != self.show_resume {
             if show {
-                self.menu.menu_items.insert(0, RESUME);
-            } else {
+                self.menu.menu_items.insert(0, (RESUME, MenuAction::Resume));
+            } else if !self.menu.menu_items.is_empty() {
                 self.menu.menu_items.remove(0);
+                self.menu.reset_selection();
             }
 
             self.show_resume = show;
@@ -48,13 +205,36 @@ impl<'a> MainMenu<'a> {
 impl<'a> event::EventHandler for MainMenu<'a> {
     fn update(&mut self, ctx: &mut ggez::Context, gctx: &mut event::GraphicsContext) -> Result<(), ggez::GameError> {
         self.menu.update(ctx, gctx)?;
+        self.title_pulse.advance_and_maybe_wrap(timer::delta(ctx).as_secs_f64());
 
         Ok(())        
     }
 
     fn draw(&mut self, ctx: &mut ggez::Context, gctx: &mut event::GraphicsContext) -> Result<(), ggez::GameError> {
+        let theme = self.menu.theme;
+        let from: TweenableColor = ColorPalette::Fg.color_for(theme).into();
+        let to: TweenableColor = ColorPalette::BrightYellow.color_for(theme).into();
+        let tint = self.title_pulse.now() * TITLE_PULSE_STRENGTH;
+        self.menu.set_title_color(ease(Linear, from, to, tint).into());
+
         self.menu.draw(ctx, gctx)?;
 
+        let (screen_width, screen_height) = graphics::drawable_size(gctx);
+
+        let rendered = Text::new(
+            TextFragment::new(format!("Best: {}", self.high_score))
+                .font(self.menu.font)
+                .scale(scaled(32.0, screen_height))
+                .color(ColorPalette::Fg4.color_for(theme))
+        );
+
+        let position = Point2::new(
+            screen_width / 2.0 - rendered.width(ctx) / 2.0,
+            screen_height - rendered.height(ctx) - V_PADDING,
+        );
+
+        graphics::draw(ctx, gctx, &rendered, (position,))?;
+
         Ok(())        
     }
 
@@ -68,30 +248,124 @@ impl<'a> event::EventHandler for MainMenu<'a> {
         ) {
         self.menu.key_down_event(ctx, gctx, keycode, keymods, repeat)
     }
+
+    fn mouse_motion_event(
+            &mut self,
+            ctx: &mut ggez::Context,
+            gctx: &mut event::GraphicsContext,
+            x: f32,
+            y: f32,
+            dx: f32,
+            dy: f32,
+        ) {
+        self.menu.mouse_motion_event(ctx, gctx, x, y, dx, dy)
+    }
+
+    fn mouse_button_down_event(
+            &mut self,
+            ctx: &mut ggez::Context,
+            gctx: &mut event::GraphicsContext,
+            button: MouseButton,
+            x: f32,
+            y: f32,
+        ) {
+        self.menu.mouse_button_down_event(ctx, gctx, button, x, y)
+    }
 }
 
 pub struct Menu<'a> {
     title: &'a str,
-    menu_items: Vec<&'a str>,
-    shade_background: bool,
+    /// Overrides the theme's foreground color for the title.
+    title_color: Option<Color>,
+    /// A smaller line under the title, e.g. the score on the pause menu.
+    subtitle: Option<String>,
+    menu_items: Vec<(&'a str, MenuAction)>,
+    /// A line explaining each item, shown while it's selected.
+    descriptions: Vec<Option<String>>,
+    /// Opacity of the full-screen shade behind the menu, if it has one.
+    shade_alpha: Option<u8>,
     shade_menu_items: bool,
 
     selected_index: usize,
+    /// Index of the first item in the visible window.
+    scroll_offset: usize,
+    max_visible: usize,
+    /// Bounds of each visible item as of the last draw, used for mouse hit-testing.
+    item_rects: Vec<Rect>,
+    cache: MenuCache,
+    /// Where the selection box is, as a fractional item index, sliding toward `selected_index`.
+    cursor: AnimationSequence<f32>,
+    font: Font,
+    theme: Theme,
+}
+
+/// Seconds the selection box takes to slide from one item to the next.
+const CURSOR_SLIDE_SECS: f64 = 0.15;
+
+fn cursor_slide(from: f32, to: f32) -> AnimationSequence<f32> {
+    let mut animation = AnimationSequence::new();
+    let _ = animation.insert(Keyframe::new(from, 0.0, EaseOut));
+    let _ = animation.insert(Keyframe::new(to, CURSOR_SLIDE_SECS, EaseOut));
+
+    animation
 }
 
 impl<'a> Menu<'a> {
-    pub fn new(title: &'a str, menu_items: &[&'a str]) -> Self {
+    pub fn new(title: &'a str, menu_items: &[(&'a str, MenuAction)]) -> Self {
         Self {
             title,
+            title_color: None,
+            subtitle: None,
             menu_items: menu_items.to_vec(),
-            shade_background: false,
+            descriptions: vec![],
+            shade_alpha: None,
             shade_menu_items: false,
-            selected_index: 0
+            selected_index: 0,
+            scroll_offset: 0,
+            max_visible: DEFAULT_MAX_VISIBLE,
+            item_rects: vec![],
+            cache: MenuCache::default(),
+            cursor: cursor_slide(0.0, 0.0),
+            font: Font::default(),
+            theme: Theme::default(),
         }
     }
 
-    pub fn shade_background(mut self, val: bool) -> Self {
-        self.shade_background = val;
+    pub fn set_font(&mut self, font: Font) {
+        self.font = font;
+    }
+
+    pub fn set_theme(&mut self, theme: Theme) {
+        self.theme = theme;
+    }
+
+    pub fn set_title_color(&mut self, color: Color) {
+        self.title_color = Some(color);
+    }
+
+    pub fn set_subtitle(&mut self, subtitle: Option<String>) {
+        self.subtitle = subtitle;
+    }
+
+    /// Describes the items, in the same order; items past the end of `descriptions` have none.
+    pub fn with_descriptions(mut self, descriptions: &[Option<&str>]) -> Self {
+        self.descriptions = descriptions.iter()
+            .map(|description| description.map(str::to_string))
+            .collect();
+
+        self
+    }
+
+    /// Limits how many items are drawn at once; the rest are reached by scrolling.
+    pub fn max_visible(mut self, val: usize) -> Self {
+        self.max_visible = val.max(1);
+
+        self
+    }
+
+    /// Dims whatever is behind the menu with a shade of the given opacity.
+    pub fn shade_background(mut self, alpha: u8) -> Self {
+        self.shade_alpha = Some(alpha);
 
         self
     }
@@ -104,29 +378,82 @@ impl<'a> Menu<'a> {
 
     pub fn reset_selection(&mut self) {
         self.selected_index = 0;
+        self.scroll_offset = 0;
+        self.cursor = cursor_slide(0.0, 0.0);
     }
 
-    pub fn selected_item(&self) -> &str {
-        &self.menu_items[self.selected_index]
+    /// Moves the selection to the item with `action`, leaving it unchanged if there is none.
+    pub fn select_action(&mut self, action: MenuAction) {
+        if let Some(i) = self.menu_items.iter().position(|(_, item_action)| *item_action == action) {
+            self.selected_index = i;
+            self.cursor = cursor_slide(i as f32, i as f32);
+            self.scroll_to_selection();
+        }
     }
 
-    fn next_selection(&mut self) {
-        self.selected_index = (self.selected_index + 1) % self.menu_items.len()
+    /// Where the selection box currently is, as a fractional item index.
+    pub fn cursor_position(&self) -> f32 {
+        self.cursor.now()
     }
 
-    fn prev_selection(&mut self) {
-        self.selected_index = {
+    /// Selects item `i`, sliding the selection box over from wherever it is now.
+    fn select(&mut self, i: usize) {
+        if i != self.selected_index {
+            self.cursor = cursor_slide(self.cursor_position(), i as f32);
+            self.selected_index = i;
+        }
+
+        self.scroll_to_selection();
+    }
+
+    pub fn selected_item(&self) -> Option<&str> {
+        self.menu_items.get(self.selected_index).map(|(label, _)| *label)
+    }
+
+    pub fn selected_action(&self) -> Option<MenuAction> {
+        self.menu_items.get(self.selected_index).map(|(_, action)| *action)
+    }
+
+    pub fn selected_description(&self) -> Option<&str> {
+        self.descriptions.get(self.selected_index)?.as_deref()
+    }
+
+    /// Index of the item under the given point, if any.
+    pub fn item_at(&self, x: f32, y: f32) -> Option<usize> {
+        hit_test(&self.item_rects, x, y).map(|i| i + self.scroll_offset)
+    }
+
+    fn scroll_to_selection(&mut self) {
+        self.scroll_offset = scroll_to(self.selected_index, self.scroll_offset, self.max_visible);
+    }
+
+    pub fn next_selection(&mut self) {
+        if self.menu_items.is_empty() {
+            return;
+        }
+
+        self.select((self.selected_index + 1) % self.menu_items.len());
+    }
+
+    pub fn prev_selection(&mut self) {
+        if self.menu_items.is_empty() {
+            return;
+        }
+
+        self.select({
             if self.selected_index == 0 {
                 self.menu_items.len() - 1
             } else {
                 self.selected_index - 1
             }
-        }
+        });
     }
 }
 
 impl<'a> EventHandler for Menu<'a> {
-    fn update(&mut self, _ctx: &mut ggez::Context, _quad_ctx: &mut event::GraphicsContext) -> Result<(), ggez::GameError> {
+    fn update(&mut self, ctx: &mut ggez::Context, _quad_ctx: &mut event::GraphicsContext) -> Result<(), ggez::GameError> {
+        self.cursor.advance_by(timer::delta(ctx).as_secs_f64());
+
         Ok(())
     }
 
@@ -146,93 +473,640 @@ impl<'a> EventHandler for Menu<'a> {
         
     }
 
+    fn mouse_motion_event(
+            &mut self,
+            _ctx: &mut ggez::Context,
+            _quad_ctx: &mut event::GraphicsContext,
+            x: f32,
+            y: f32,
+            _dx: f32,
+            _dy: f32,
+        ) {
+        if let Some(i) = self.item_at(x, y) {
+            self.select(i);
+        }
+    }
+
+    fn mouse_button_down_event(
+            &mut self,
+            _ctx: &mut ggez::Context,
+            _quad_ctx: &mut event::GraphicsContext,
+            button: MouseButton,
+            x: f32,
+            y: f32,
+        ) {
+        if button == MouseButton::Left {
+            if let Some(i) = self.item_at(x, y) {
+                self.select(i);
+            }
+        }
+    }
+
     fn draw(&mut self, ctx: &mut ggez::Context, gctx: &mut event::GraphicsContext) -> Result<(), ggez::GameError> {
+        let labels: Vec<&str> = self.menu_items.iter().map(|(label, _)| *label).collect();
+        let cursor = self.cursor_position();
+        let title_color = self.title_color.unwrap_or_else(|| ColorPalette::Fg.color_for(self.theme));
 
-        let (screen_width, screen_height) = graphics::drawable_size(gctx);
+        self.item_rects = draw_menu(
+            ctx,
+            gctx,
+            &mut self.cache,
+            Heading { title: self.title, subtitle: self.subtitle.as_deref(), color: title_color },
+            &labels,
+            Scroll {
+                cursor,
+                selected: self.selected_index,
+                offset: &mut self.scroll_offset,
+                max_visible: self.max_visible,
+            },
+            self.shade_alpha,
+            self.font,
+            self.theme,
+        )?;
 
-        if self.shade_background {
-            let shade = graphics::MeshBuilder::new()
-                    .rectangle(
-                        DrawMode::fill(), 
-                        Rect::new(
-                            0.0,
-                            0.0,
-                            screen_width,
-                            screen_height,
-                        ), 
-                        ColorPalette::TransparentBg.into(),
-                    )?
-                    .build(ctx, gctx)?;
-
-                graphics::draw(ctx, gctx, &shade, (Point2::new(0.0, 0.0),))?;
+        if let Some(description) = self.descriptions.get(self.selected_index).and_then(Option::as_deref) {
+            draw_description(ctx, gctx, &mut self.cache.description, description, self.font, self.theme)?;
         }
 
-        let mut position = Point2::new(screen_width, screen_height / 3.0);
+        let hidden_below = self.scroll_offset + self.item_rects.len() < self.menu_items.len();
+        draw_scroll_arrows(ctx, gctx, &self.item_rects, self.scroll_offset > 0, hidden_below, self.theme)?;
 
-        let rendered = Text::new(
-            TextFragment::new(self.title)
-                .scale(96.0)
-                .color(ColorPalette::Fg)
-        );
+        Ok(())
+    }
+}
+
+struct SettingRow<'a> {
+    label: &'a str,
+    description: &'a str,
+    options: Vec<&'a str>,
+    selected_index: usize,
+}
+
+impl<'a> SettingRow<'a> {
+    fn new(label: &'a str, description: &'a str, options: &[&'a str], selected_index: usize) -> Self {
+        Self {
+            label,
+            description,
+            options: options.to_vec(),
+            selected_index,
+        }
+    }
+
+    fn value(&self) -> &'a str {
+        self.options[self.selected_index]
+    }
+
+    fn next_value(&mut self) {
+        self.selected_index = (self.selected_index + 1) % self.options.len()
+    }
+
+    fn prev_value(&mut self) {
+        self.selected_index = {
+            if self.selected_index == 0 {
+                self.options.len() - 1
+            } else {
+                self.selected_index - 1
+            }
+        }
+    }
+}
+
+/// A menu of adjustable rows, where Left/Right change the selected row's value.
+pub struct SettingsMenu<'a> {
+    rows: Vec<SettingRow<'a>>,
+    selected_index: usize,
+    /// Index of the first row in the visible window.
+    scroll_offset: usize,
+    max_visible: usize,
+    /// Bounds of each visible row as of the last draw, used for mouse hit-testing.
+    item_rects: Vec<Rect>,
+    cache: MenuCache,
+    font: Font,
+    theme: Theme,
+}
+
+impl<'a> Default for SettingsMenu<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> SettingsMenu<'a> {
+    pub fn new() -> Self {
+        Self {
+            rows: vec![
+                SettingRow::new(DIFFICULTY, "How fast words come at you", &[EASY, NORMAL, HARD], 1),
+                SettingRow::new(
+                    VOLUME,
+                    "Loudness of the sound effects",
+                    &[VOLUME_OFF, VOLUME_25, VOLUME_50, VOLUME_75, VOLUME_100],
+                    4,
+                ),
+                SettingRow::new(
+                    THEME,
+                    "Colors, including sets for deuteranopia and high contrast",
+                    &[THEME_DEFAULT, THEME_DEUTERANOPIA, THEME_HIGH_CONTRAST],
+                    0,
+                ),
+                SettingRow::new(LAYOUT, "The keyboard layout you type with", &[LAYOUT_QWERTY, LAYOUT_DVORAK], 0),
+                SettingRow::new(
+                    GAME_SPEED,
+                    "Slows words and animations down for more time to react",
+                    &[SPEED_NORMAL, SPEED_SLOW, SPEED_SLOWER],
+                    0,
+                ),
+                SettingRow::new(TRAJECTORIES, "Draws the path each word is taking toward you", &[OFF, ON], 0),
+                SettingRow::new(KEY_HINTS, "Shows the keys to press beneath each word", &[OFF, ON], 0),
+                SettingRow::new(CASE_SENSITIVE, "Capital letters need shift held", &[OFF, ON], 0),
+                SettingRow::new(HIGH_VISIBILITY, "Typed letters in bright yellow instead of fading out", &[OFF, ON], 0),
+                SettingRow::new(
+                    PLAYER_COLOR,
+                    "The color of your orb",
+                    &[PLAYER_ORANGE, PLAYER_YELLOW, PLAYER_GREEN, PLAYER_BLUE],
+                    0,
+                ),
+                SettingRow::new(
+                    SPAWN_PATTERN,
+                    "Words come in from a ring above you, or from the edges of the screen",
+                    &[SPAWN_RING, SPAWN_EDGES],
+                    0,
+                ),
+            ],
+            selected_index: 0,
+            scroll_offset: 0,
+            max_visible: DEFAULT_MAX_VISIBLE,
+            item_rects: vec![],
+            cache: MenuCache::default(),
+            font: Font::default(),
+            theme: Theme::default(),
+        }
+    }
+
+    pub fn set_font(&mut self, font: Font) {
+        self.font = font;
+    }
+
+    pub fn set_theme(&mut self, theme: Theme) {
+        self.theme = theme;
+    }
+
+    /// Current value of the row with the given label.
+    pub fn value(&self, label: &str) -> Option<&'a str> {
+        self.rows.iter()
+            .find(|row| row.label == label)
+            .map(|row| row.value())
+    }
+
+    /// Every row's label alongside its current value.
+    pub fn values(&self) -> impl Iterator<Item = (&'a str, &'a str)> + '_ {
+        self.rows.iter().map(|row| (row.label, row.value()))
+    }
+
+    /// Sets the row with the given label to `value`, if it is one of that row's options.
+    pub fn set_value(&mut self, label: &str, value: &str) {
+        if let Some(row) = self.rows.iter_mut().find(|row| row.label == label) {
+            if let Some(i) = row.options.iter().position(|option| *option == value) {
+                row.selected_index = i;
+            }
+        }
+    }
+
+    pub fn reset_selection(&mut self) {
+        self.selected_index = 0;
+        self.scroll_offset = 0;
+    }
+
+    pub fn item_at(&self, x: f32, y: f32) -> Option<usize> {
+        hit_test(&self.item_rects, x, y).map(|i| i + self.scroll_offset)
+    }
+
+    fn select(&mut self, i: usize) {
+        self.selected_index = i;
+        self.scroll_offset = scroll_to(i, self.scroll_offset, self.max_visible);
+    }
+
+    fn next_selection(&mut self) {
+        self.select((self.selected_index + 1) % self.rows.len())
+    }
+
+    fn prev_selection(&mut self) {
+        self.select({
+            if self.selected_index == 0 {
+                self.rows.len() - 1
+            } else {
+                self.selected_index - 1
+            }
+        })
+    }
+}
+
+impl<'a> EventHandler for SettingsMenu<'a> {
+    fn update(&mut self, _ctx: &mut ggez::Context, _quad_ctx: &mut event::GraphicsContext) -> Result<(), ggez::GameError> {
+        Ok(())
+    }
+
+    fn key_down_event(
+            &mut self,
+            _ctx: &mut ggez::Context,
+            _quad_ctx: &mut event::GraphicsContext,
+            keycode: KeyCode,
+            _keymods: event::KeyMods,
+            _repeat: bool,
+        ) {
+        match keycode {
+            KeyCode::Up => self.prev_selection(),
+            KeyCode::Down => self.next_selection(),
+            KeyCode::Left => self.rows[self.selected_index].prev_value(),
+            KeyCode::Right => self.rows[self.selected_index].next_value(),
+            _ => (),
+        }
+    }
+
+    fn mouse_motion_event(
+            &mut self,
+            _ctx: &mut ggez::Context,
+            _quad_ctx: &mut event::GraphicsContext,
+            x: f32,
+            y: f32,
+            _dx: f32,
+            _dy: f32,
+        ) {
+        if let Some(i) = self.item_at(x, y) {
+            self.select(i);
+        }
+    }
+
+    fn mouse_button_down_event(
+            &mut self,
+            _ctx: &mut ggez::Context,
+            _quad_ctx: &mut event::GraphicsContext,
+            button: MouseButton,
+            x: f32,
+            y: f32,
+        ) {
+        if button == MouseButton::Left {
+            if let Some(i) = self.item_at(x, y) {
+                self.select(i);
+                self.rows[i].next_value();
+            }
+        }
+    }
 
-        position.x = screen_width / 2.0 - rendered.width(ctx) / 2.0;
+    fn draw(&mut self, ctx: &mut ggez::Context, gctx: &mut event::GraphicsContext) -> Result<(), ggez::GameError> {
+        let labels: Vec<String> = self.rows.iter()
+            .map(|row| format!("{}: < {} >", row.label, row.value()))
+            .collect();
 
-        graphics::draw(
+        self.item_rects = draw_menu(
             ctx,
             gctx,
-            &rendered,
-            (position,),
+            &mut self.cache,
+            Heading { title: SETTINGS_MENU_TITLE, subtitle: None, color: ColorPalette::Fg.color_for(self.theme) },
+            &labels,
+            Scroll {
+                cursor: self.selected_index as f32,
+                selected: self.selected_index,
+                offset: &mut self.scroll_offset,
+                max_visible: self.max_visible,
+            },
+            None,
+            self.font,
+            self.theme,
         )?;
 
-        position.y += rendered.height(ctx) + V_PADDING * 3.0;
-
-        for (i, menu_item) in self.menu_items.iter().enumerate() {
-            let color = {
-                if i == self.selected_index {
-                    ColorPalette::BrightYellow
-                } else {
-                    ColorPalette::Fg
-                }
-            };
-
-            let rendered = Text::new(
-                TextFragment::new(*menu_item)
-                    .scale(48.0)
-                    .color(color)
-            );
-
-            position.x = screen_width / 2.0 - rendered.width(ctx) / 2.0;
-
-            if i == self.selected_index {
-                
-                // draw selection box
-                
-                let image = graphics::MeshBuilder::new()
-                    .rectangle(
-                        DrawMode::stroke(3.0), 
-                        Rect::new(
-                            -5.0,
-                            -5.0,
-                            rendered.width(ctx) + 10.0,
-                            rendered.height(ctx) + 10.0,
-                        ), 
-                        color.into(),
-                    )?
-                    .build(ctx, gctx)?;
-
-                graphics::draw(ctx, gctx, &image, (position,))?;
+        if let Some(row) = self.rows.get(self.selected_index) {
+            draw_description(ctx, gctx, &mut self.cache.description, row.description, self.font, self.theme)?;
+        }
+
+        let hidden_below = self.scroll_offset + self.item_rects.len() < self.rows.len();
+        draw_scroll_arrows(ctx, gctx, &self.item_rects, self.scroll_offset > 0, hidden_below, self.theme)?;
+
+        Ok(())
+    }
+}
+
+/// A rectangle mesh along with the bounds and color it was built with.
+struct CachedRect {
+    mesh: Mesh,
+    rect: Rect,
+    color: Color,
+}
+
+/// Meshes and text kept between frames rather than rebuilt on every draw.
+#[derive(Default)]
+struct MenuCache {
+    shade: Option<CachedRect>,
+    selection_box: Option<CachedRect>,
+    title: TextCache<LabelKey>,
+    subtitle: TextCache<LabelKey>,
+    items: Vec<TextCache<LabelKey>>,
+    /// An item measured before any are drawn, to work out how many fit.
+    item_sample: TextCache<LabelKey>,
+    description: TextCache<LabelKey>,
+}
+
+/// Everything a title or item's text is built from.
+#[derive(Clone, Debug, PartialEq)]
+struct LabelKey {
+    label: String,
+    scale: f32,
+    color: Color,
+    font: Font,
+}
+
+impl LabelKey {
+    fn new(label: &str, scale: f32, color: Color, font: Font) -> Self {
+        Self { label: label.to_string(), scale, color, font }
+    }
+
+    fn build(&self) -> Text {
+        Text::new(
+            TextFragment::new(self.label.as_str())
+                .font(self.font)
+                .scale(self.scale)
+                .color(self.color)
+        )
+    }
+}
+
+/// Returns the cached mesh, first rebuilding it if `rect` or `color` differ from what it was built with.
+fn cached_rect<'m>(
+    ctx: &mut ggez::Context,
+    gctx: &mut event::GraphicsContext,
+    cache: &'m mut Option<CachedRect>,
+    mode: DrawMode,
+    rect: Rect,
+    color: Color,
+) -> Result<&'m Mesh, ggez::GameError> {
+    let fresh = matches!(cache, Some(cached) if cached.rect == rect && cached.color == color);
+
+    if !fresh {
+        let mesh = graphics::MeshBuilder::new()
+            .rectangle(mode, rect, color)?
+            .build(ctx, gctx)?;
+
+        *cache = Some(CachedRect { mesh, rect, color });
+    }
+
+    Ok(&cache.as_ref().expect("cache was just filled").mesh)
+}
+
+/// The title, and the smaller line under it if any, drawn above a menu's items.
+struct Heading<'h> {
+    title: &'h str,
+    subtitle: Option<&'h str>,
+    color: Color,
+}
+
+/// Where a menu's selection is, and which of its items are scrolled into view.
+struct Scroll<'s> {
+    /// The item the selection box is around, which may be fractional while it slides between items.
+    cursor: f32,
+    selected: usize,
+    /// Index of the first item drawn, moved as needed to keep `selected` in view.
+    offset: &'s mut usize,
+    max_visible: usize,
+}
+
+/// Draws a heading with a vertical list of items beneath it, returning the bounds of each item
+/// drawn. Only as many items as fit in the window, up to `scroll.max_visible`, are drawn; the item
+/// nearest the selection box is highlighted.
+#[allow(clippy::too_many_arguments)]
+fn draw_menu<S: AsRef<str>>(
+    ctx: &mut ggez::Context,
+    gctx: &mut event::GraphicsContext,
+    cache: &mut MenuCache,
+    heading: Heading,
+    menu_items: &[S],
+    scroll: Scroll,
+    shade_alpha: Option<u8>,
+    font: Font,
+    theme: Theme,
+) -> Result<Vec<Rect>, ggez::GameError> {
+
+    let (screen_width, screen_height) = graphics::drawable_size(gctx);
+
+    if let Some(alpha) = shade_alpha {
+        let (r, g, b, _) = ColorPalette::TransparentBg.color_for(theme).to_rgba();
+
+        let shade = cached_rect(
+            ctx,
+            gctx,
+            &mut cache.shade,
+            DrawMode::fill(),
+            Rect::new(
+                0.0,
+                0.0,
+                screen_width,
+                screen_height,
+            ),
+            Color::from_rgba(r, g, b, alpha),
+        )?;
+
+        graphics::draw(ctx, gctx, shade, (Point2::new(0.0, 0.0),))?;
+    }
+
+    let mut position = Point2::new(screen_width, screen_height / 8.0);
+
+    cache.title.set_key(LabelKey::new(heading.title, scaled(96.0, screen_height), heading.color, font));
+    let (rendered, (width, height)) = cache.title.get(LabelKey::build, |text| (text.width(ctx), text.height(ctx)));
+
+    position.x = screen_width / 2.0 - width / 2.0;
+
+    graphics::draw(
+        ctx,
+        gctx,
+        rendered,
+        (position,),
+    )?;
+
+    position.y += height;
+
+    if let Some(subtitle) = heading.subtitle {
+        position.y += V_PADDING / 2.0;
+
+        cache.subtitle.set_key(LabelKey::new(subtitle, scaled(32.0, screen_height), ColorPalette::Fg4.color_for(theme), font));
+        let (rendered, (width, height)) = cache.subtitle.get(LabelKey::build, |text| (text.width(ctx), text.height(ctx)));
+
+        position.x = screen_width / 2.0 - width / 2.0;
+
+        graphics::draw(ctx, gctx, rendered, (position,))?;
+
+        position.y += height;
+    }
+
+    position.y += V_PADDING;
+
+    let item_scale = scaled(48.0, screen_height);
+    let sample = menu_items.first().map_or("", AsRef::as_ref);
+    cache.item_sample.set_key(LabelKey::new(sample, item_scale, ColorPalette::Fg.color_for(theme), font));
+    let (_, (_, item_height)) = cache.item_sample.get(LabelKey::build, |text| (text.width(ctx), text.height(ctx)));
+
+    // leaves room along the bottom for a description or the high score
+    let visible = items_that_fit(position.y, screen_height - V_PADDING * 2.0, item_height).min(scroll.max_visible);
+    *scroll.offset = scroll_to(scroll.selected, *scroll.offset, visible).min(menu_items.len().saturating_sub(visible));
+
+    let menu_items = &menu_items[*scroll.offset..(*scroll.offset + visible).min(menu_items.len())];
+
+    let mut item_rects = vec![];
+    let cursor = (scroll.cursor - *scroll.offset as f32).clamp(0.0, menu_items.len().saturating_sub(1) as f32);
+    let selected_index = cursor.round() as usize;
+
+    cache.items.resize_with(menu_items.len(), TextCache::default);
+
+    for (i, (menu_item, item_cache)) in menu_items.iter().zip(cache.items.iter_mut()).enumerate() {
+        let color = {
+            if i == selected_index {
+                ColorPalette::BrightYellow.color_for(theme)
+            } else {
+                ColorPalette::Fg.color_for(theme)
             }
+        };
 
-            graphics::draw(ctx, gctx, &rendered, (position,))?;
+        item_cache.set_key(LabelKey::new(menu_item.as_ref(), item_scale, color, font));
+        let (rendered, (width, height)) = item_cache.get(LabelKey::build, |text| (text.width(ctx), text.height(ctx)));
 
-            position.y += rendered.height(ctx) + V_PADDING;
-        }
+        position.x = screen_width / 2.0 - width / 2.0;
 
+        item_rects.push(Rect::new(
+            position.x - 5.0,
+            position.y - 5.0,
+            width + 10.0,
+            height + 10.0,
+        ));
 
-        Ok(())
+        graphics::draw(ctx, gctx, rendered, (position,))?;
+
+        position.y += height + ITEM_PADDING;
+    }
+
+    if let Some(from) = item_rects.get(cursor.floor() as usize) {
+        let to = item_rects.get(cursor.ceil() as usize).unwrap_or(from);
+        let t = cursor.fract();
+        let lerp = |a: f32, b: f32| a + (b - a) * t;
+
+        // built at the origin so that sliding only moves it, and just resizing rebuilds it
+        let rect = Rect::new(0.0, 0.0, lerp(from.w, to.w), lerp(from.h, to.h));
+        let selection_box = cached_rect(
+            ctx,
+            gctx,
+            &mut cache.selection_box,
+            DrawMode::stroke(3.0),
+            rect,
+            ColorPalette::BrightYellow.color_for(theme),
+        )?;
+
+        graphics::draw(ctx, gctx, selection_box, (Point2::new(lerp(from.x, to.x), lerp(from.y, to.y)),))?;
+    }
+
+    Ok(item_rects)
+}
+
+/// The main menu's Exit item, which on the web leaves the current game rather than the page.
+pub fn exit_label() -> &'static str {
+    exit_label_for(cfg!(target_arch = "wasm32"))
+}
+
+fn exit_label_for(wasm: bool) -> &'static str {
+    if wasm {
+        BACK_TO_MENU
+    } else {
+        EXIT
     }
 }
 
+/// Draws a line explaining the selected item along the bottom of the screen.
+fn draw_description(
+    ctx: &mut ggez::Context,
+    gctx: &mut event::GraphicsContext,
+    cache: &mut TextCache<LabelKey>,
+    description: &str,
+    font: Font,
+    theme: Theme,
+) -> Result<(), ggez::GameError> {
+    let (screen_width, screen_height) = graphics::drawable_size(gctx);
+
+    cache.set_key(LabelKey::new(description, scaled(24.0, screen_height), ColorPalette::Fg4.color_for(theme), font));
+    let (rendered, (width, height)) = cache.get(LabelKey::build, |text| (text.width(ctx), text.height(ctx)));
+
+    let position = Point2::new(screen_width / 2.0 - width / 2.0, screen_height - height - V_PADDING / 2.0);
+    graphics::draw(ctx, gctx, rendered, (position,))?;
+
+    Ok(())
+}
+
+/// How many items `item_height` tall can be listed from `top` down to `bottom`, but always at least
+/// one so the selection stays in view however small the window.
+fn items_that_fit(top: f32, bottom: f32, item_height: f32) -> usize {
+    let rows = (bottom - top + ITEM_PADDING) / (item_height + ITEM_PADDING);
+
+    (rows.floor() as usize).max(1)
+}
+
+/// The scroll offset, starting from `offset`, that shifts a window of `max_visible` items just
+/// enough to include item `selected`.
+fn scroll_to(selected: usize, offset: usize, max_visible: usize) -> usize {
+    if selected < offset {
+        selected
+    } else if selected >= offset + max_visible {
+        selected + 1 - max_visible
+    } else {
+        offset
+    }
+}
+
+/// Draws arrows above and below the visible items, `item_rects`, where more are scrolled out of view.
+fn draw_scroll_arrows(
+    ctx: &mut ggez::Context,
+    gctx: &mut event::GraphicsContext,
+    item_rects: &[Rect],
+    hidden_above: bool,
+    hidden_below: bool,
+    theme: Theme,
+) -> Result<(), ggez::GameError> {
+    let (screen_width, _) = graphics::drawable_size(gctx);
+
+    if let (Some(first), Some(last)) = (item_rects.first(), item_rects.last()) {
+        if hidden_above {
+            draw_scroll_arrow(ctx, gctx, Point2::new(screen_width / 2.0, first.y - V_PADDING / 2.0), true, theme)?;
+        }
+
+        if hidden_below {
+            draw_scroll_arrow(ctx, gctx, Point2::new(screen_width / 2.0, last.y + last.h + V_PADDING / 2.0), false, theme)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Draws a small triangle centered on `position`, pointing up or down.
+fn draw_scroll_arrow(
+    ctx: &mut ggez::Context,
+    gctx: &mut event::GraphicsContext,
+    position: Point2,
+    up: bool,
+    theme: Theme,
+) -> Result<(), ggez::GameError> {
+    // clockwise, as MeshBuilder::polygon requires
+    let points = if up {
+        [Point2::new(0.0, -6.0), Point2::new(10.0, 6.0), Point2::new(-10.0, 6.0)]
+    } else {
+        [Point2::new(0.0, 6.0), Point2::new(-10.0, -6.0), Point2::new(10.0, -6.0)]
+    };
+
+    let arrow = graphics::MeshBuilder::new()
+        .polygon(DrawMode::fill(), &points, ColorPalette::Fg4.color_for(theme))?
+        .build(ctx, gctx)?;
+
+    graphics::draw(ctx, gctx, &arrow, (position,))?;
+
+    Ok(())
+}
+
+fn hit_test(rects: &[Rect], x: f32, y: f32) -> Option<usize> {
+    rects.iter().position(|rect| rect.contains(Point2::new(x, y)))
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -240,58 +1114,324 @@ mod test {
     #[test]
     fn test_next_selection() {
         let menu_items = vec![
-            "Item 1", 
-            "Item 2", 
-            "Item 3",
+            ("Item 1", MenuAction::NewGame), 
+            ("Item 2", MenuAction::Settings), 
+            ("Item 3", MenuAction::Exit),
         ];
 
         let mut menu = Menu::new("Test Title", &menu_items);
 
         assert_eq!(menu.selected_index, 0);
-        assert_eq!(menu.selected_item(), menu_items[0]);
+        assert_eq!(menu.selected_item(), Some(menu_items[0].0));
+        assert_eq!(menu.selected_action(), Some(menu_items[0].1));
 
         menu.next_selection();
 
         assert_eq!(menu.selected_index, 1);
-        assert_eq!(menu.selected_item(), menu_items[1]);
+        assert_eq!(menu.selected_item(), Some(menu_items[1].0));
+        assert_eq!(menu.selected_action(), Some(menu_items[1].1));
 
         menu.next_selection();
 
         assert_eq!(menu.selected_index, 2);
-        assert_eq!(menu.selected_item(), menu_items[2]);
+        assert_eq!(menu.selected_item(), Some(menu_items[2].0));
+        assert_eq!(menu.selected_action(), Some(menu_items[2].1));
 
         menu.next_selection();
 
         assert_eq!(menu.selected_index, 0);
-        assert_eq!(menu.selected_item(), menu_items[0]);
+        assert_eq!(menu.selected_item(), Some(menu_items[0].0));
+        assert_eq!(menu.selected_action(), Some(menu_items[0].1));
     }
 
     #[test]
     fn test_prev_selection() {
         let menu_items = vec![
-            "Item 1", 
-            "Item 2", 
-            "Item 3",
+            ("Item 1", MenuAction::NewGame), 
+            ("Item 2", MenuAction::Settings), 
+            ("Item 3", MenuAction::Exit),
         ];
 
         let mut menu = Menu::new("Test Title", &menu_items);
 
         assert_eq!(menu.selected_index, 0);
-        assert_eq!(menu.selected_item(), menu_items[0]);
+        assert_eq!(menu.selected_item(), Some(menu_items[0].0));
+        assert_eq!(menu.selected_action(), Some(menu_items[0].1));
+
+        menu.prev_selection();
+
+        assert_eq!(menu.selected_index, 2);
+        assert_eq!(menu.selected_item(), Some(menu_items[2].0));
+        assert_eq!(menu.selected_action(), Some(menu_items[2].1));
+
+        menu.prev_selection();
+
+        assert_eq!(menu.selected_index, 1);
+        assert_eq!(menu.selected_item(), Some(menu_items[1].0));
+        assert_eq!(menu.selected_action(), Some(menu_items[1].1));
 
         menu.prev_selection();
 
+        assert_eq!(menu.selected_index, 0);
+        assert_eq!(menu.selected_item(), Some(menu_items[0].0));
+        assert_eq!(menu.selected_action(), Some(menu_items[0].1));
+    }
+
+    #[test]
+    fn test_scroll_offset() {
+        let menu_items = vec![
+            ("Item 1", MenuAction::NewGame),
+            ("Item 2", MenuAction::Resume),
+            ("Item 3", MenuAction::Restart),
+            ("Item 4", MenuAction::Settings),
+            ("Item 5", MenuAction::Exit),
+        ];
+
+        let mut menu = Menu::new("Test Title", &menu_items).max_visible(3);
+
+        menu.next_selection();
+        menu.next_selection();
         assert_eq!(menu.selected_index, 2);
-        assert_eq!(menu.selected_item(), menu_items[2]);
+        assert_eq!(menu.scroll_offset, 0);
+
+        menu.next_selection();
+        assert_eq!(menu.selected_index, 3);
+        assert_eq!(menu.scroll_offset, 1);
+
+        menu.next_selection();
+        assert_eq!(menu.selected_index, 4);
+        assert_eq!(menu.scroll_offset, 2);
+
+        menu.next_selection();
+        assert_eq!(menu.selected_index, 0);
+        assert_eq!(menu.scroll_offset, 0);
 
         menu.prev_selection();
+        assert_eq!(menu.selected_index, 4);
+        assert_eq!(menu.scroll_offset, 2);
+
+        menu.prev_selection();
+        menu.prev_selection();
+        assert_eq!(menu.selected_index, 2);
+        assert_eq!(menu.scroll_offset, 2);
 
+        menu.prev_selection();
         assert_eq!(menu.selected_index, 1);
-        assert_eq!(menu.selected_item(), menu_items[1]);
+        assert_eq!(menu.scroll_offset, 1);
+
+        menu.select_action(MenuAction::Exit);
+        assert_eq!(menu.scroll_offset, 2);
+
+        menu.reset_selection();
+        assert_eq!(menu.scroll_offset, 0);
+    }
+
+    #[test]
+    fn test_empty_menu() {
+        let mut menu = Menu::new("Test Title", &[]);
 
+        assert_eq!(menu.selected_item(), None);
+        assert_eq!(menu.selected_action(), None);
+
+        menu.next_selection();
         menu.prev_selection();
 
         assert_eq!(menu.selected_index, 0);
-        assert_eq!(menu.selected_item(), menu_items[0]);
+        assert_eq!(menu.selected_item(), None);
+    }
+
+    #[test]
+    fn test_pause_menu_has_restart() {
+        let menu = Menu::new(PAUSE_MENU_TITLE, &PAUSE_MENU_ITEMS);
+
+        assert!(menu.menu_items.contains(&(RESTART, MenuAction::Restart)));
+    }
+
+    #[test]
+    fn test_title_pulse_loops() {
+        let mut pulse = title_pulse();
+        let start = pulse.now();
+
+        pulse.advance_and_maybe_wrap(TITLE_PULSE_SECS / 2.0);
+        assert_ne!(pulse.now(), start);
+
+        pulse.advance_and_maybe_wrap(TITLE_PULSE_SECS / 2.0);
+        assert_eq!(pulse.now(), start);
+
+        pulse.advance_and_maybe_wrap(TITLE_PULSE_SECS * 1.5);
+        assert_eq!(pulse.now(), 1.0);
+    }
+
+    #[test]
+    fn test_exit_label() {
+        assert_eq!(exit_label_for(false), EXIT);
+        assert_eq!(exit_label_for(true), BACK_TO_MENU);
+
+        #[cfg(not(target_arch = "wasm32"))]
+        assert_eq!(exit_label(), EXIT);
+        #[cfg(target_arch = "wasm32")]
+        assert_eq!(exit_label(), BACK_TO_MENU);
+    }
+
+    #[test]
+    fn test_selected_description() {
+        let mut menu = Menu::new("Test Title", &[
+            (EASY, MenuAction::NewGame),
+            (NORMAL, MenuAction::NewGame),
+            (HARD, MenuAction::NewGame),
+        ]);
+        assert_eq!(menu.selected_description(), None);
+
+        menu = menu.with_descriptions(&[Some("Gentle"), None]);
+        assert_eq!(menu.selected_description(), Some("Gentle"));
+
+        menu.next_selection();
+        assert_eq!(menu.selected_description(), None);
+
+        // more items than descriptions
+        menu.next_selection();
+        assert_eq!(menu.selected_description(), None);
+    }
+
+    #[test]
+    fn test_subtitle() {
+        let mut menu = Menu::new(PAUSE_MENU_TITLE, &PAUSE_MENU_ITEMS);
+        assert_eq!(menu.subtitle, None);
+
+        menu.set_subtitle(Some("Score: 120  Level: 3".to_string()));
+        assert_eq!(menu.subtitle.as_deref(), Some("Score: 120  Level: 3"));
+
+        menu.set_subtitle(None);
+        assert_eq!(menu.subtitle, None);
+    }
+
+    #[test]
+    fn test_shade_background_alpha() {
+        let menu = Menu::new("Test Title", &[]);
+        assert_eq!(menu.shade_alpha, None);
+
+        let menu = menu.shade_background(180);
+        assert_eq!(menu.shade_alpha, Some(180));
+    }
+
+    #[test]
+    fn test_hit_test() {
+        let rects = vec![
+            Rect::new(100.0, 100.0, 200.0, 50.0),
+            Rect::new(100.0, 185.0, 200.0, 50.0),
+        ];
+
+        assert_eq!(hit_test(&rects, 150.0, 120.0), Some(0));
+        assert_eq!(hit_test(&rects, 299.0, 230.0), Some(1));
+        assert_eq!(hit_test(&rects, 150.0, 170.0), None);
+        assert_eq!(hit_test(&rects, 50.0, 120.0), None);
+        assert_eq!(hit_test(&[], 150.0, 120.0), None);
+    }
+
+    #[test]
+    fn test_setting_value_cycling() {
+        let mut settings = SettingsMenu::new();
+
+        assert_eq!(settings.value(DIFFICULTY), Some(NORMAL));
+        assert_eq!(settings.value(THEME), Some(THEME_DEFAULT));
+
+        settings.rows[0].next_value();
+        assert_eq!(settings.value(DIFFICULTY), Some(HARD));
+
+        settings.rows[0].next_value();
+        assert_eq!(settings.value(DIFFICULTY), Some(EASY));
+
+        settings.rows[0].prev_value();
+        assert_eq!(settings.value(DIFFICULTY), Some(HARD));
+
+        settings.next_selection();
+        settings.rows[settings.selected_index].next_value();
+        assert_eq!(settings.value(VOLUME), Some(VOLUME_OFF));
+
+        settings.rows[settings.selected_index].prev_value();
+        assert_eq!(settings.value(VOLUME), Some(VOLUME_100));
+
+        assert_eq!(settings.value("Nonexistent"), None);
+    }
+
+    #[test]
+    fn test_settings_scroll() {
+        let mut settings = SettingsMenu::new();
+        let last = settings.rows.len() - 1;
+        assert!(last >= DEFAULT_MAX_VISIBLE, "enough rows to need scrolling");
+
+        settings.prev_selection();
+        assert_eq!(settings.selected_index, last);
+        assert_eq!(settings.scroll_offset, last + 1 - DEFAULT_MAX_VISIBLE);
+
+        settings.next_selection();
+        assert_eq!(settings.selected_index, 0);
+        assert_eq!(settings.scroll_offset, 0);
+
+        for _ in 0..DEFAULT_MAX_VISIBLE {
+            settings.next_selection();
+        }
+        assert_eq!(settings.scroll_offset, 1);
+
+        settings.reset_selection();
+        assert_eq!(settings.scroll_offset, 0);
+    }
+
+    #[test]
+    fn test_items_that_fit() {
+        // the default 800x600 window, with a 96px title and 48px items
+        let top = 600.0 / 8.0 + 96.0 + V_PADDING;
+        let bottom = 600.0 - V_PADDING * 2.0;
+        let visible = items_that_fit(top, bottom, 48.0);
+        assert_eq!(visible, 5);
+        assert!(top + visible as f32 * (48.0 + ITEM_PADDING) - ITEM_PADDING <= bottom);
+
+        assert_eq!(items_that_fit(0.0, 100.0, 100.0), 1);
+        assert_eq!(items_that_fit(0.0, 100.0 + ITEM_PADDING + 50.0, 50.0), 2);
+        assert_eq!(items_that_fit(500.0, 100.0, 48.0), 1, "the selection is always drawn");
+    }
+
+    #[test]
+    fn test_main_menu_shows_every_item() {
+        let mut main_menu = MainMenu::new();
+        main_menu.show_resume(true);
+
+        assert_eq!(main_menu.menu.max_visible, main_menu.menu.menu_items.len());
+    }
+
+    #[test]
+    fn test_setting_set_value() {
+        let mut settings = SettingsMenu::new();
+
+        settings.set_value(THEME, THEME_HIGH_CONTRAST);
+        assert_eq!(settings.value(THEME), Some(THEME_HIGH_CONTRAST));
+
+        settings.set_value(THEME, "Not a theme");
+        assert_eq!(settings.value(THEME), Some(THEME_HIGH_CONTRAST));
+    }
+
+    #[test]
+    fn test_cursor_slides_to_selection() {
+        let mut menu = Menu::new("Test", &[
+            ("One", MenuAction::NewGame),
+            ("Two", MenuAction::Settings),
+            ("Three", MenuAction::Exit),
+        ]);
+        assert_eq!(menu.cursor_position(), 0.0);
+
+        menu.next_selection();
+        assert_eq!(menu.selected_index, 1);
+        assert_eq!(menu.cursor_position(), 0.0);
+
+        menu.cursor.advance_by(CURSOR_SLIDE_SECS / 2.0);
+        let halfway = menu.cursor_position();
+        assert!(halfway > 0.0 && halfway < 1.0);
+
+        menu.cursor.advance_by(CURSOR_SLIDE_SECS);
+        assert_eq!(menu.cursor_position(), 1.0);
+
+        // picking a menu's starting item doesn't slide
+        menu.select_action(MenuAction::Exit);
+        assert_eq!(menu.cursor_position(), 2.0);
     }
 }
@@ -0,0 +1,85 @@
+use std::f32::consts::PI;
+
+use good_web_game::graphics::{Point2, Vector2};
+use rand::Rng;
+
+use crate::ColorPalette;
+
+pub const PARTICLES_PER_BURST: usize = 12;
+
+const PARTICLE_LIFETIME: f64 = 0.6;
+const PARTICLE_COLORS: [ColorPalette; 3] = [ColorPalette::BrightYellow, ColorPalette::Orange, ColorPalette::Red];
+
+pub struct Particle {
+    pub position: Point2,
+    /// Pixels per second.
+    pub velocity: Vector2,
+    /// Seconds left before the particle disappears.
+    pub lifetime: f64,
+    pub color: ColorPalette,
+}
+
+impl Particle {
+    pub fn tick(&mut self, dt: f64) {
+        self.position += self.velocity * dt as f32;
+        self.lifetime -= dt;
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.lifetime <= 0.0
+    }
+}
+
+/// A ring of particles flying outward from `origin`, with speeds and lifetimes drawn from `rng`.
+pub fn burst(origin: Point2, rng: &mut impl Rng) -> Vec<Particle> {
+    (0..PARTICLES_PER_BURST)
+        .map(|i| {
+            let angle = i as f32 / PARTICLES_PER_BURST as f32 * 2.0 * PI;
+            let speed = rng.gen_range(60.0..140.0);
+
+            Particle {
+                position: origin,
+                velocity: Vector2::new(angle.cos() * speed, angle.sin() * speed),
+                lifetime: PARTICLE_LIFETIME * rng.gen_range(0.7..=1.0),
+                color: PARTICLE_COLORS[i % PARTICLE_COLORS.len()],
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+
+    #[test]
+    fn test_particle_tick() {
+        let mut particle = Particle {
+            position: Point2::new(0.0, 0.0),
+            velocity: Vector2::new(10.0, -20.0),
+            lifetime: 1.0,
+            color: ColorPalette::Red,
+        };
+
+        particle.tick(0.5);
+        assert_eq!(particle.position, Point2::new(5.0, -10.0));
+        assert!(!particle.is_expired());
+
+        particle.tick(0.5);
+        assert!(particle.is_expired());
+    }
+
+    #[test]
+    fn test_burst_is_seeded() {
+        let summary = |seed: u64| -> Vec<(Vector2, f64)> {
+            burst(Point2::new(10.0, 20.0), &mut StdRng::seed_from_u64(seed)).iter()
+                .map(|particle| (particle.velocity, particle.lifetime))
+                .collect()
+        };
+
+        assert_eq!(summary(5).len(), PARTICLES_PER_BURST);
+        assert_eq!(summary(5), summary(5));
+        assert_ne!(summary(5), summary(6));
+    }
+}
@@ -0,0 +1,63 @@
+/// Mean of the most recent samples, kept in a fixed-size ring buffer.
+pub struct RollingAverage {
+    samples: Vec<f64>,
+    capacity: usize,
+    /// Where the next sample goes once the buffer is full.
+    next: usize,
+}
+
+impl RollingAverage {
+    pub fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+
+        Self {
+            samples: Vec::with_capacity(capacity),
+            capacity,
+            next: 0,
+        }
+    }
+
+    pub fn push(&mut self, sample: f64) {
+        if self.samples.len() < self.capacity {
+            self.samples.push(sample);
+        } else {
+            self.samples[self.next] = sample;
+        }
+
+        self.next = (self.next + 1) % self.capacity;
+    }
+
+    pub fn average(&self) -> f64 {
+        if self.samples.is_empty() {
+            return 0.0;
+        }
+
+        self.samples.iter().sum::<f64>() / self.samples.len() as f64
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_rolling_average() {
+        let mut average = RollingAverage::new(3);
+        assert_eq!(average.average(), 0.0);
+
+        average.push(30.0);
+        assert_eq!(average.average(), 30.0);
+
+        average.push(60.0);
+        average.push(90.0);
+        assert_eq!(average.average(), 60.0);
+
+        // the oldest sample is replaced once full
+        average.push(120.0);
+        assert_eq!(average.average(), 90.0);
+
+        average.push(120.0);
+        average.push(120.0);
+        assert_eq!(average.average(), 120.0);
+    }
+}
@@ -0,0 +1,34 @@
+use crate::{read_data_file, write_data_file};
+
+const HIGH_SCORE_FILE: &str = "high_score.txt";
+
+/// Reads the best score from disk, defaulting to 0 if it is missing or unreadable.
+pub fn load_high_score() -> usize {
+    read_data_file(HIGH_SCORE_FILE).map_or(0, |contents| parse_high_score(&contents))
+}
+
+pub fn save_high_score(score: usize) {
+    write_data_file(HIGH_SCORE_FILE, &score.to_string());
+}
+
+fn parse_high_score(contents: &str) -> usize {
+    contents.trim().parse().unwrap_or(0)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_high_score() {
+        assert_eq!(parse_high_score("120"), 120);
+        assert_eq!(parse_high_score("  4500\n"), 4500);
+    }
+
+    #[test]
+    fn test_parse_high_score_failure() {
+        assert_eq!(parse_high_score(""), 0);
+        assert_eq!(parse_high_score("not a number"), 0);
+        assert_eq!(parse_high_score("-12"), 0);
+    }
+}
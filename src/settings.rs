@@ -0,0 +1,84 @@
+use crate::{read_data_file, write_data_file};
+
+const SETTINGS_FILE: &str = "settings.txt";
+
+/// The settings menu's choices, saved between sessions as `label = value` lines keyed by the
+/// menu's own row labels and option names.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Settings {
+    values: Vec<(String, String)>,
+}
+
+impl Settings {
+    pub fn from_values<'s>(values: impl IntoIterator<Item = (&'s str, &'s str)>) -> Self {
+        Self {
+            values: values.into_iter()
+                .map(|(label, value)| (label.to_string(), value.to_string()))
+                .collect(),
+        }
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.values.iter().map(|(label, value)| (label.as_str(), value.as_str()))
+    }
+
+    /// Reads the saved settings, falling back to the defaults if they are missing or malformed.
+    pub fn load() -> Self {
+        match read_data_file(SETTINGS_FILE) {
+            Some(contents) => parse_settings(&contents).unwrap_or_else(|e| {
+                eprintln!("warning: ignoring {SETTINGS_FILE}: {e}");
+                Settings::default()
+            }),
+            None => Settings::default(),
+        }
+    }
+
+    pub fn save(&self) {
+        write_data_file(SETTINGS_FILE, &self.serialize());
+    }
+
+    fn serialize(&self) -> String {
+        self.values()
+            .map(|(label, value)| format!("{label} = {value}\n"))
+            .collect()
+    }
+}
+
+fn parse_settings(contents: &str) -> Result<Settings, String> {
+    let mut values = vec![];
+
+    for (i, line) in contents.lines().enumerate() {
+        let line = line.trim();
+
+        if line.is_empty() {
+            continue;
+        }
+
+        let (label, value) = line.split_once('=')
+            .ok_or_else(|| format!("line {}: expected `label = value`", i + 1))?;
+
+        values.push((label.trim(), value.trim()));
+    }
+
+    Ok(Settings::from_values(values))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let settings = Settings::from_values([("Difficulty", "Hard"), ("Volume", "25%"), ("Game Speed", "Slower")]);
+
+        assert_eq!(settings.serialize(), "Difficulty = Hard\nVolume = 25%\nGame Speed = Slower\n");
+        assert_eq!(parse_settings(&settings.serialize()), Ok(settings));
+    }
+
+    #[test]
+    fn test_parse_malformed() {
+        assert_eq!(parse_settings(""), Ok(Settings::default()));
+        assert!(parse_settings("Difficulty Hard").is_err());
+        assert!(parse_settings("Theme = Default\n\u{0}garbage").is_err());
+    }
+}
@@ -0,0 +1,90 @@
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Stats {
+    pub total_keystrokes: usize,
+    pub correct_keystrokes: usize,
+    pub mistakes: usize,
+    /// Seconds of active play.
+    pub elapsed: f64,
+}
+
+impl Stats {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn record_keystroke(&mut self, correct: bool) {
+        self.total_keystrokes += 1;
+
+        if correct {
+            self.correct_keystrokes += 1;
+        } else {
+            self.mistakes += 1;
+        }
+    }
+
+    pub fn advance(&mut self, dt: f64) {
+        self.elapsed += dt;
+    }
+
+    /// Words per minute, counting every five correct keystrokes as one word.
+    pub fn wpm(&self) -> f64 {
+        let minutes = self.elapsed / 60.0;
+
+        if minutes <= 0.0 {
+            return 0.0;
+        }
+
+        (self.correct_keystrokes as f64 / 5.0) / minutes
+    }
+
+    /// Fraction of keystrokes that were correct, from 0.0 to 1.0.
+    pub fn accuracy(&self) -> f64 {
+        if self.total_keystrokes == 0 {
+            return 1.0;
+        }
+
+        self.correct_keystrokes as f64 / self.total_keystrokes as f64
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_wpm() {
+        let mut stats = Stats::new();
+        assert_eq!(stats.wpm(), 0.0);
+
+        stats.correct_keystrokes = 50;
+        stats.elapsed = 60.0;
+        assert_eq!(stats.wpm(), 10.0);
+
+        stats.correct_keystrokes = 12;
+        stats.elapsed = 45.0;
+        assert!((stats.wpm() - 3.2).abs() < 1e-9);
+
+        stats.correct_keystrokes = 7;
+        stats.elapsed = 30.0;
+        assert!((stats.wpm() - 2.8).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_accuracy() {
+        let mut stats = Stats::new();
+        assert_eq!(stats.accuracy(), 1.0);
+
+        for _ in 0..9 {
+            stats.record_keystroke(true);
+        }
+        stats.record_keystroke(false);
+
+        assert_eq!(stats.total_keystrokes, 10);
+        assert_eq!(stats.mistakes, 1);
+        assert_eq!(stats.accuracy(), 0.9);
+
+        stats.record_keystroke(false);
+        stats.record_keystroke(false);
+        assert_eq!(stats.accuracy(), 0.75);
+    }
+}
@@ -0,0 +1,99 @@
+use good_web_game::graphics::Text;
+
+/// A `Text` kept between frames along with its measured size, both only redone once the key
+/// describing its content, colors and scale changes.
+pub struct TextCache<K> {
+    key: Option<K>,
+    text: Option<(Text, (f32, f32))>,
+    /// Whether `text` is out of date with `key`.
+    dirty: bool,
+}
+
+impl<K> Default for TextCache<K> {
+    fn default() -> Self {
+        Self { key: None, text: None, dirty: true }
+    }
+}
+
+impl<K: PartialEq> TextCache<K> {
+    /// Marks the cache dirty if `key` differs from what the text was built from.
+    pub fn set_key(&mut self, key: K) {
+        if self.key.as_ref() != Some(&key) {
+            self.key = Some(key);
+            self.dirty = true;
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn is_dirty(&self) -> bool {
+        self.dirty
+    }
+
+    /// The text and its width and height, first rebuilt with `build` and remeasured with
+    /// `measure` if dirty.
+    pub fn get(
+        &mut self,
+        build: impl FnOnce(&K) -> Text,
+        measure: impl FnOnce(&Text) -> (f32, f32),
+    ) -> (&Text, (f32, f32)) {
+        if self.dirty || self.text.is_none() {
+            let text = build(self.key.as_ref().expect("key is set before the text is read"));
+            let extents = measure(&text);
+
+            self.text = Some((text, extents));
+            self.dirty = false;
+        }
+
+        let (text, extents) = self.text.as_ref().expect("text was just built");
+
+        (text, *extents)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use std::cell::Cell;
+
+    use good_web_game::graphics::TextFragment;
+
+    #[test]
+    fn test_extents_are_cached_until_dirty() {
+        let builds = Cell::new(0);
+        let measures = Cell::new(0);
+        let mut cache = TextCache::default();
+
+        let get = |cache: &mut TextCache<(&str, f32)>| {
+            let (_, extents) = cache.get(
+                |(label, scale)| {
+                    builds.set(builds.get() + 1);
+                    Text::new(TextFragment::new(*label).scale(*scale))
+                },
+                |_| {
+                    measures.set(measures.get() + 1);
+                    (10.0, 5.0)
+                },
+            );
+
+            extents
+        };
+
+        cache.set_key(("Play", 24.0));
+        assert!(cache.is_dirty());
+        assert_eq!(get(&mut cache), (10.0, 5.0));
+        assert!(!cache.is_dirty());
+
+        cache.set_key(("Play", 24.0));
+        get(&mut cache);
+        get(&mut cache);
+        assert_eq!((builds.get(), measures.get()), (1, 1));
+
+        cache.set_key(("Play", 48.0));
+        assert!(cache.is_dirty());
+        get(&mut cache);
+        cache.set_key(("Quit", 48.0));
+        get(&mut cache);
+        assert_eq!((builds.get(), measures.get()), (3, 3));
+    }
+}
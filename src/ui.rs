@@ -0,0 +1,137 @@
+use ggez::{
+    event::{self, KeyCode},
+    graphics::{self, Color, Font, Point2, Text, TextFragment},
+    timer, Context, GameResult,
+};
+
+use crate::{color_scheme::{ColorPalette, Theme}, word::keycode_to_ch};
+
+/// Seconds the cursor spends shown, then hidden.
+const CURSOR_BLINK_SECS: f64 = 0.5;
+
+/// A line of text typed in by the player, e.g. a name for the leaderboard.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TextInput {
+    text: String,
+    max_len: usize,
+}
+
+impl TextInput {
+    pub fn new(max_len: usize) -> Self {
+        Self {
+            text: String::new(),
+            max_len,
+        }
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn clear(&mut self) {
+        self.text.clear();
+    }
+
+    /// Adds `ch` to the end, unless the text is already at its maximum length.
+    pub fn push(&mut self, ch: char) {
+        if self.text.chars().count() < self.max_len {
+            self.text.push(ch);
+        }
+    }
+
+    pub fn backspace(&mut self) {
+        self.text.pop();
+    }
+
+    /// Types the character for `key_code`, capitalized if `shift`, or deletes one for Backspace.
+    pub fn key_down_event(&mut self, key_code: KeyCode, shift: bool) {
+        if key_code == KeyCode::Backspace {
+            self.backspace();
+        } else if let Some(ch) = keycode_to_ch(key_code) {
+            self.push(if shift { ch.to_ascii_uppercase() } else { ch });
+        }
+    }
+
+    /// Draws the text in `font` and `theme`'s colors centered horizontally on `position`, followed
+    /// by a blinking cursor, and returns its height.
+    pub fn draw(
+        &self,
+        ctx: &mut Context,
+        gctx: &mut event::GraphicsContext,
+        position: Point2,
+        font: Font,
+        scale: f32,
+        theme: Theme,
+    ) -> GameResult<f32> {
+        // the cursor is kept in the text while hidden so the text doesn't shift as it blinks
+        let text_color = ColorPalette::Fg.color_for(theme);
+        let cursor_color = if cursor_visible(timer::time_since_start(ctx).as_secs_f64()) {
+            text_color
+        } else {
+            Color::new(0.0, 0.0, 0.0, 0.0)
+        };
+
+        let mut rendered = Text::new(TextFragment::new(self.text.as_str()).font(font).scale(scale).color(text_color));
+        rendered.add(TextFragment::new("_").font(font).scale(scale).color(cursor_color));
+
+        let position = Point2::new(position.x - rendered.width(ctx) / 2.0, position.y);
+        graphics::draw(ctx, gctx, &rendered, (position,))?;
+
+        Ok(rendered.height(ctx))
+    }
+}
+
+fn cursor_visible(secs: f64) -> bool {
+    ((secs / CURSOR_BLINK_SECS) as u64).is_multiple_of(2)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_append() {
+        let mut input = TextInput::new(8);
+
+        input.key_down_event(KeyCode::A, true);
+        input.key_down_event(KeyCode::L, false);
+        input.key_down_event(KeyCode::LeftShift, true);
+        input.push(' ');
+
+        assert_eq!(input.text(), "Al ");
+    }
+
+    #[test]
+    fn test_backspace() {
+        let mut input = TextInput::new(8);
+
+        input.key_down_event(KeyCode::Backspace, false);
+        assert_eq!(input.text(), "");
+
+        input.push('a');
+        input.push('b');
+        input.key_down_event(KeyCode::Backspace, false);
+        assert_eq!(input.text(), "a");
+    }
+
+    #[test]
+    fn test_max_length() {
+        let mut input = TextInput::new(3);
+
+        for key_code in [KeyCode::A, KeyCode::B, KeyCode::C, KeyCode::D] {
+            input.key_down_event(key_code, false);
+        }
+        assert_eq!(input.text(), "abc");
+
+        input.backspace();
+        input.push('z');
+        assert_eq!(input.text(), "abz");
+    }
+
+    #[test]
+    fn test_cursor_blinks() {
+        assert!(cursor_visible(0.0));
+        assert!(!cursor_visible(CURSOR_BLINK_SECS + 0.1));
+        assert!(cursor_visible(CURSOR_BLINK_SECS * 2.0 + 0.1));
+    }
+}
@@ -4,9 +4,10 @@ use good_web_game::{
     Context,
     event, 
     GameResult, 
-    GameError,
     graphics::{
         self,
+        Color,
+        Font,
         Point2,
         Text, 
         TextFragment,
@@ -15,9 +16,13 @@ use good_web_game::{
     input::keyboard::KeyCode,
 };
 
-use keyframe::{functions::{EaseInOut, Linear}, AnimationSequence, Keyframe };
+use keyframe::{ease, functions::{EaseInOut, EaseOut, Linear}, AnimationSequence, Keyframe };
 
 use crate::{
+    color_scheme::Theme,
+    fonts::scaled,
+    keymap::{Keymap, Layout},
+    text_cache::TextCache,
     ColorPalette, 
     TweenableColor
 };
@@ -30,6 +35,72 @@ pub enum WordState {
     Dead,
 }
 
+/// The colors a word flashes through as it dies, themed to the words being played.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum DeathPalette {
+    #[default]
+    Default,
+    /// Runs through the colors of the rainbow.
+    Rainbow,
+    /// Fades through greens.
+    Greens,
+}
+
+impl DeathPalette {
+    fn animation(self, duration: f64, theme: Theme) -> AnimationSequence<TweenableColor> {
+        let keyframes: &[(ColorPalette, f64)] = match self {
+            Self::Default => &[
+                (ColorPalette::Red, 0.0),
+                (ColorPalette::Fg0, 0.05),
+                (ColorPalette::Blue, 0.45),
+                (ColorPalette::Bg, 1.0),
+            ],
+            Self::Rainbow => &[
+                (ColorPalette::Red, 0.0),
+                (ColorPalette::Orange, 0.2),
+                (ColorPalette::BrightYellow, 0.4),
+                (ColorPalette::Green, 0.6),
+                (ColorPalette::Blue, 0.8),
+                (ColorPalette::Bg, 1.0),
+            ],
+            Self::Greens => &[
+                (ColorPalette::Green, 0.0),
+                (ColorPalette::Fg0, 0.05),
+                (ColorPalette::Green, 0.45),
+                (ColorPalette::Bg2, 0.75),
+                (ColorPalette::Bg, 1.0),
+            ],
+        };
+
+        let mut animation = AnimationSequence::new();
+        for (i, (color, at)) in keyframes.iter().enumerate() {
+            // a quick flash at the start, then easing into each color after
+            let color = color.color_for(theme).into();
+            let keyframe = if i < 2 {
+                Keyframe::new(color, duration * at, Linear)
+            } else {
+                Keyframe::new(color, duration * at, EaseInOut)
+            };
+
+            let _ = animation.insert(keyframe);
+        }
+
+        animation
+    }
+}
+
+/// What happened to a word as a result of a keypress or tick.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum WordEvent {
+    None,
+    /// The keypress advanced the word by a character.
+    CharTyped,
+    /// The last character was typed and the death animation has begun.
+    Completed,
+    /// The death animation finished.
+    Died,
+}
+
 pub struct Word {
     pub state: WordState,
     pub num_typed: usize,
@@ -38,17 +109,57 @@ pub struct Word {
     position: Point2,
     velocity: Vector2,
     color: ColorPalette,
+    theme: Theme,
+    /// Where the word is headed and how far away it started, for the danger cue.
+    target: Option<(Point2, f32)>,
+    strict_mode: bool,
+    /// Whether keystrokes can advance the word at all.
+    typeable: bool,
+    /// Purely visual words, like the ring labels, which never change state.
+    decorative: bool,
+    /// Whether the last key fed to this word advanced it.
+    just_advanced: bool,
+    /// Game time the first character was typed at.
+    first_typed_at: Option<f32>,
+    /// Game time the last character was typed at.
+    completed_at: Option<f32>,
+    death_palette: DeathPalette,
     death_animation: AnimationSequence<TweenableColor>,
+    /// How big the word is drawn relative to normal as it dies: it pops up, then shrinks away.
+    death_scale: AnimationSequence<f32>,
+    /// Fades the word in from the background when it first appears.
+    spawn_animation: AnimationSequence<TweenableColor>,
+    /// The rendered text, kept between frames since most frames draw it unchanged.
+    text: TextCache<TextKey>,
+    /// The key hint drawn beneath the word, cached the same way.
+    hint_text: TextCache<HintKey>,
+}
+
+/// What a word's text is built from, so it only needs rebuilding when one of these changes.
+#[derive(Copy, Clone, Debug, PartialEq)]
+struct TextKey {
+    num_typed: usize,
+    /// Typed, next and untyped colors, at the precision they're drawn at, since colors that
+    /// shift slightly every frame would otherwise rebuild the text every frame.
+    colors: [(u8, u8, u8, u8); 3],
+    scale: f32,
+    font: Font,
+}
+
+/// What a word's key hint is built from. The word itself never changes, so the layout stands in
+/// for the hint's text.
+#[derive(Copy, Clone, Debug, PartialEq)]
+struct HintKey {
+    layout: Layout,
+    color: (u8, u8, u8, u8),
+    scale: f32,
+    font: Font,
 }
 
 impl Word {
     pub fn new(word: &str, position: Point2, velocity: Vector2) -> Self {
-        let animation_duration = 1.0;
-        let mut death_animation = AnimationSequence::new();
-        let _ = death_animation.insert(Keyframe::new(ColorPalette::Red.into(), 0.0, Linear));
-        let _ = death_animation.insert(Keyframe::new(ColorPalette::Fg0.into(), animation_duration * 0.05, Linear));
-        let _ = death_animation.insert(Keyframe::new(ColorPalette::Blue.into(), animation_duration * 0.45, EaseInOut));
-        let _ = death_animation.insert(Keyframe::new(ColorPalette::Bg.into(), animation_duration, EaseInOut));
+        let animation_duration = DEATH_ANIMATION_SECS;
+        let death_animation = DeathPalette::default().animation(animation_duration, Theme::default());
 
         Self { 
             word: word.chars().collect(), 
@@ -56,8 +167,21 @@ impl Word {
             position, 
             velocity,
             color: ColorPalette::Fg,
+            theme: Theme::default(),
+            target: None,
+            strict_mode: false,
+            typeable: true,
+            decorative: false,
+            just_advanced: false,
+            first_typed_at: None,
+            completed_at: None,
             state: WordState::Active,
+            death_palette: DeathPalette::default(),
             death_animation,
+            death_scale: death_scale(animation_duration),
+            spawn_animation: spawn_animation(ColorPalette::Fg, Theme::default()),
+            text: TextCache::default(),
+            hint_text: TextCache::default(),
             // death_animation: keyframes![
             //     (Color::from(ColorPalette::BrightYellow), 0.0, Linear),
             //     (Color::from(ColorPalette::Fg0), animation_duration * 0.05, Linear),
@@ -67,68 +191,352 @@ impl Word {
 
     pub fn with_color(mut self, color: ColorPalette) -> Self {
         self.color = color;
+        self.spawn_animation = spawn_animation(color, self.theme);
+
+        self
+    }
+
+    pub fn with_strict(mut self, strict_mode: bool) -> Self {
+        self.strict_mode = strict_mode;
+
+        self
+    }
+
+    pub fn with_death_palette(mut self, palette: DeathPalette) -> Self {
+        self.death_palette = palette;
+        self.death_animation = palette.animation(DEATH_ANIMATION_SECS, self.theme);
+
+        self
+    }
+
+    pub fn with_theme(mut self, theme: Theme) -> Self {
+        self.set_theme(theme);
+
+        self
+    }
+
+    /// Recolors the word for `theme`, picking its animations up where they left off.
+    pub fn set_theme(&mut self, theme: Theme) {
+        self.theme = theme;
+
+        let spawn_time = self.spawn_animation.time();
+        self.spawn_animation = spawn_animation(self.color, theme);
+        self.spawn_animation.advance_to(spawn_time);
+
+        let death_time = self.death_animation.time();
+        self.death_animation = self.death_palette.animation(DEATH_ANIMATION_SECS, theme);
+        self.death_animation.advance_to(death_time);
+    }
+
+    /// Tints the word toward red as it closes in on `target`.
+    pub fn with_target(mut self, target: Point2) -> Self {
+        self.target = Some((target, distance(self.position, target)));
+
+        self
+    }
+
+    /// Marks the word as scenery: it can't be typed and stays `Active` for good.
+    pub fn decorative(mut self) -> Self {
+        self.decorative = true;
+        self.typeable = false;
 
         self
     }
 
-    pub fn update(&mut self, ctx: &mut Context, _gctx: &mut event::GraphicsContext, key_pressed: Option<KeyCode>) -> GameResult {
+    pub fn is_decorative(&self) -> bool {
+        self.decorative
+    }
+
+    pub fn text(&self) -> String {
+        self.word.iter().collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.word.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.word.is_empty()
+    }
+
+    pub fn position(&self) -> Point2 {
+        self.position
+    }
+
+    pub fn velocity(&self) -> Vector2 {
+        self.velocity
+    }
+
+    /// Stretches the word's position and velocity by the given factors, e.g. after a resize.
+    pub fn rescale(&mut self, scale_x: f32, scale_y: f32) {
+        self.position.x *= scale_x;
+        self.position.y *= scale_y;
+        self.velocity.x *= scale_x;
+        self.velocity.y *= scale_y;
+    }
+
+    /// Advances the word by `dt` seconds of game time, moving it at `speed` times its velocity in
+    /// pixels per second, e.g. 0.0 while frozen. Moving by time rather than by call keeps words
+    /// at the same speed whatever the frame rate.
+    ///
+    /// This is the only place the death animation advances, and it is only reached through
+    /// `Game::step`, which `GameManager` never runs while paused. So pausing freezes a dying word
+    /// mid-animation instead of letting it finish under the pause menu.
+    pub fn tick(&mut self, dt: f64, speed: f32) -> WordEvent {
+        if !self.spawn_animation.finished() {
+            if self.num_typed == 0 {
+                self.spawn_animation.advance_by(dt);
+            } else {
+                // no need to keep fading once the player has started on it
+                self.spawn_animation.advance_to(self.spawn_animation.duration());
+            }
+        }
+
+        if self.decorative {
+            return WordEvent::None;
+        }
+
         if self.state == WordState::Typed && self.death_animation.finished() {
             self.state = WordState::Dead;
+
+            return WordEvent::Died;
         }
 
         if self.state == WordState::Typed {
-            self.death_animation.advance_by(ggez::timer::delta(ctx).as_secs_f64());
+            self.death_animation.advance_by(dt);
+            self.death_scale.advance_by(dt);
+        }
+
+        if self.num_typed < self.word.len() {
+            self.position += self.velocity * (speed * dt as f32);
+
+        } else if self.state == WordState::Active {
+            self.state = WordState::Typed;
+
+            return WordEvent::Completed;
+        }
+
+        WordEvent::None
+    }
+
+    /// Feeds a keypress, made `now` seconds into the game, to the word. Characters the keymap
+    /// can't type are skipped over, so words from user-supplied lists can always be finished.
+    /// Unless `case_sensitive`, letters match whether or not shift is held.
+    pub fn type_key(
+        &mut self,
+        key_pressed: KeyCode,
+        shift: bool,
+        case_sensitive: bool,
+        keymap: &Keymap,
+        now: f32,
+    ) -> WordEvent {
+        self.just_advanced = false;
+
+        if !self.typeable {
+            return WordEvent::None;
         }
 
-        if let Some(next_ch) = self.word.get(self.num_typed) {
-            if let Some(key_pressed) = key_pressed {
-                let key_code = ch_to_keycode(*next_ch)
-                    .ok_or_else(|| GameError::CustomError(format!("unmapped character: {next_ch}")))?;
+        if key_pressed == KeyCode::Backspace {
+            // a finished word is on its way out, so there's nothing left to undo
+            if self.state == WordState::Active && self.num_typed < self.word.len() {
+                self.num_typed = self.num_typed.saturating_sub(1);
+            }
+
+            return WordEvent::None;
+        }
+
+        let next = self.word[self.num_typed..].iter()
+            .enumerate()
+            .find_map(|(i, ch)| keymap.key_for(*ch).map(|key| (self.num_typed + i, *ch, key)));
+
+        if let Some((i, ch, (key_code, needs_shift))) = next {
+            let shift_matches = shift == needs_shift || (!case_sensitive && ch.is_alphabetic());
 
-                if key_pressed == key_code {
-                    self.num_typed += 1;
+            let key_matches = key_pressed == key_code || numpad_keycode(ch) == Some(key_pressed);
+
+            if key_matches && shift_matches {
+                self.num_typed = i + 1;
+                self.just_advanced = true;
+                self.skip_unmapped(keymap);
+
+                self.first_typed_at.get_or_insert(now);
+                if self.num_typed == self.word.len() {
+                    self.completed_at = Some(now);
                 }
 
+                return WordEvent::CharTyped;
+            } else if self.strict_mode && keymap.is_typing_key(key_pressed) {
+                self.num_typed = 0;
             }
-            self.position += self.velocity;
+        }
 
-        } else if self.state == WordState::Active {
-            self.state = WordState::Typed;
+        WordEvent::None
+    }
+
+    /// Seconds from typing the first character to typing the last, once the word is finished.
+    pub fn completion_time(&self) -> Option<f32> {
+        Some(self.completed_at? - self.first_typed_at?)
+    }
+
+    /// Advances past any characters at the cursor that `keymap` has no key for.
+    fn skip_unmapped(&mut self, keymap: &Keymap) {
+        while self.word.get(self.num_typed).is_some_and(|ch| keymap.key_for(*ch).is_none()) {
+            self.num_typed += 1;
         }
-        
-        Ok(())
     }
 
-    pub fn draw(&mut self, ctx: &mut Context, gctx: &mut event::GraphicsContext) -> GameResult {
-        let typed_color = match self.state {
-            WordState::Active => ColorPalette::Bg4.into(),
-            WordState::Typed => self.death_animation.now_strict().unwrap_or_else(|| ColorPalette::Bg.into()),
-            WordState::Dead => ColorPalette::Bg.into(),
-        };
+    /// How close the word is to its target, from 0.0 where it started to 1.0 on arrival, in steps
+    /// of `1 / DANGER_STEPS` so that its color, and so its text, only changes now and then.
+    fn danger(&self) -> f32 {
+        match self.target {
+            Some((target, start_distance)) if start_distance > 0.0 => {
+                let danger = 1.0 - (distance(self.position, target) / start_distance).clamp(0.0, 1.0);
+
+                (danger * DANGER_STEPS).round() / DANGER_STEPS
+            },
+            _ => 0.0,
+        }
+    }
+
+    fn untyped_color(&self, high_visibility: bool) -> Color {
+        if !self.spawn_animation.finished() {
+            if let Some(color) = self.spawn_animation.now_strict() {
+                return color.into();
+            }
+        }
+
+        if high_visibility {
+            ColorPalette::Fg.color_for(self.theme)
+        } else {
+            danger_color(self.color, self.danger(), self.theme)
+        }
+    }
+
+    /// Color of the next character to type, lit up while the player's last keystroke hit it.
+    fn next_char_color(&self, high_visibility: bool) -> Color {
+        if self.state == WordState::Active && self.num_typed > 0 && self.just_advanced {
+            ColorPalette::BrightYellow.color_for(self.theme)
+        } else {
+            self.untyped_color(high_visibility)
+        }
+    }
 
-        let untyped_color = self.color;
+    /// How big the word is drawn relative to normal.
+    fn scale_factor(&self) -> f32 {
+        match self.state {
+            WordState::Active => 1.0,
+            WordState::Typed => self.death_scale.now_strict().unwrap_or(0.0),
+            WordState::Dead => 0.0,
+        }
+    }
+
+    /// Color of the letters already typed, which fade into the background unless `high_visibility`
+    /// is on.
+    fn typed_color(&self, high_visibility: bool) -> Color {
+        match self.state {
+            WordState::Active if high_visibility => ColorPalette::BrightYellow.color_for(self.theme),
+            WordState::Active => ColorPalette::Bg4.color_for(self.theme),
+            WordState::Typed => self.death_animation.now_strict().map_or_else(|| ColorPalette::Bg.color_for(self.theme), Color::from),
+            WordState::Dead => ColorPalette::Bg.color_for(self.theme),
+        }
+    }
+
+    fn text_key(&self, font: Font, scale: f32, high_visibility: bool) -> TextKey {
+        TextKey {
+            num_typed: self.num_typed,
+            colors: [
+                self.typed_color(high_visibility).to_rgba(),
+                self.next_char_color(high_visibility).to_rgba(),
+                self.untyped_color(high_visibility).to_rgba(),
+            ],
+            scale,
+            font,
+        }
+    }
 
-        let typed = 
-            TextFragment::new(self.word[0..self.num_typed].iter().collect::<String>())
-            .scale(24.0)
-            .color(typed_color);
+    fn build_text(word: &[char], key: &TextKey) -> Text {
+        let [typed_color, next_color, untyped_color] = key.colors.map(|(r, g, b, a)| Color::from_rgba(r, g, b, a));
 
-        let mut rendered = Text::new(typed);
-        rendered.add(
-            TextFragment::new(self.word[self.num_typed..].iter().collect::<String>())
-                .scale(24.0)
-                .color(untyped_color)
+        let mut rendered = Text::new(
+            TextFragment::new(word[0..key.num_typed].iter().collect::<String>())
+                .font(key.font)
+                .scale(key.scale)
+                .color(typed_color)
         );
 
-        // rendered.add(
-        //     TextFragment::new(format!(" state: {:#?}", self.state)).color(ColorPalette::Fg4)
-        // );
+        if let Some((next_ch, rest)) = word[key.num_typed..].split_first() {
+            rendered.add(
+                TextFragment::new(*next_ch)
+                    .font(key.font)
+                    .scale(key.scale)
+                    .color(next_color)
+            );
+            rendered.add(
+                TextFragment::new(rest.iter().collect::<String>())
+                    .font(key.font)
+                    .scale(key.scale)
+                    .color(untyped_color)
+            );
+        }
+
+        rendered
+    }
 
-        let centered_position = Point2::new(
-            self.position.x - rendered.width(ctx) / 2.0,
-            self.position.y - rendered.height(ctx) / 2.0
+    /// Draws the word in `font` centered on its position, with the keys to press for it under
+    /// `hint_keymap` shown beneath it, if given. `high_visibility` makes typed letters stand out.
+    pub fn draw(
+        &mut self,
+        ctx: &mut Context,
+        gctx: &mut event::GraphicsContext,
+        font: Font,
+        hint_keymap: Option<&Keymap>,
+        high_visibility: bool,
+    ) -> GameResult {
+        let (screen_width, screen_height) = graphics::drawable_size(gctx);
+
+        let scale_factor = self.scale_factor();
+        if scale_factor <= 0.0 {
+            return Ok(());
+        }
+
+        self.text.set_key(self.text_key(font, scaled(24.0, screen_height) * scale_factor, high_visibility));
+
+        let (rendered, (width, height)) = self.text.get(
+            |key| Self::build_text(&self.word, key),
+            |text| (text.width(ctx), text.height(ctx)),
         );
-        graphics::draw(ctx, gctx, &rendered, (centered_position,))?;
+
+        let centered_position = text_position(self.position, width, height, screen_width, screen_height);
+        graphics::draw(ctx, gctx, rendered, (centered_position,))?;
+
+        if let Some(keymap) = hint_keymap.filter(|_| !self.decorative && self.state == WordState::Active) {
+            self.hint_text.set_key(HintKey {
+                layout: keymap.layout(),
+                color: ColorPalette::Fg4.color_for(self.theme).to_rgba(),
+                scale: scaled(16.0, screen_height),
+                font,
+            });
+
+            let (hint, (hint_width, _)) = self.hint_text.get(
+                |key| {
+                    let (r, g, b, a) = key.color;
+
+                    Text::new(
+                        TextFragment::new(keymap.key_hint(&self.word.iter().collect::<String>()))
+                            .font(key.font)
+                            .scale(key.scale)
+                            .color(Color::from_rgba(r, g, b, a))
+                    )
+                },
+                |text| (text.width(ctx), text.height(ctx)),
+            );
+
+            let hint_position = Point2::new(
+                centered_position.x + (width - hint_width) / 2.0,
+                centered_position.y + height,
+            );
+            graphics::draw(ctx, gctx, hint, (hint_position,))?;
+        }
 
         Ok(())
     }
@@ -136,7 +544,143 @@ impl Word {
 }
 
 
-fn ch_to_keycode(ch: char) -> Option<KeyCode> {
+/// Where to draw text of the given size centered on `center`. Once the center is on screen the text
+/// is kept wholly on screen too, while words still flying in from off screen are left alone.
+fn text_position(center: Point2, width: f32, height: f32, screen_width: f32, screen_height: f32) -> Point2 {
+    let position = Point2::new(center.x - width / 2.0, center.y - height / 2.0);
+
+    let on_screen = (0.0..=screen_width).contains(&center.x) && (0.0..=screen_height).contains(&center.y);
+    if !on_screen {
+        return position;
+    }
+
+    Point2::new(
+        position.x.min(screen_width - width).max(0.0),
+        position.y.min(screen_height - height).max(0.0),
+    )
+}
+
+const SPAWN_ANIMATION_SECS: f64 = 0.5;
+const DEATH_ANIMATION_SECS: f64 = 1.0;
+/// How many shades a word passes through on its way from its own color to red.
+const DANGER_STEPS: f32 = 10.0;
+/// How big a dying word gets at the peak of its pop.
+const DEATH_POP_SCALE: f32 = 1.4;
+
+fn death_scale(duration: f64) -> AnimationSequence<f32> {
+    let mut animation = AnimationSequence::new();
+    let _ = animation.insert(Keyframe::new(1.0, 0.0, EaseOut));
+    let _ = animation.insert(Keyframe::new(DEATH_POP_SCALE, duration * 0.15, EaseInOut));
+    let _ = animation.insert(Keyframe::new(0.0, duration, Linear));
+
+    animation
+}
+
+fn spawn_animation(color: ColorPalette, theme: Theme) -> AnimationSequence<TweenableColor> {
+    let mut animation = AnimationSequence::new();
+    let _ = animation.insert(Keyframe::new(ColorPalette::Bg.color_for(theme).into(), 0.0, Linear));
+    let _ = animation.insert(Keyframe::new(color.color_for(theme).into(), SPAWN_ANIMATION_SECS, EaseInOut));
+
+    animation
+}
+
+fn distance(a: Point2, b: Point2) -> f32 {
+    let dx = a.x - b.x;
+    let dy = a.y - b.y;
+
+    (dx * dx + dy * dy).sqrt()
+}
+
+/// Blends `base` toward red as `danger` goes from 0.0 to 1.0.
+fn danger_color(base: ColorPalette, danger: f32, theme: Theme) -> Color {
+    let from: TweenableColor = base.color_for(theme).into();
+    let to: TweenableColor = ColorPalette::Red.color_for(theme).into();
+
+    ease(Linear, from, to, danger).into()
+}
+
+/// Whether every character of `word` can be typed.
+pub fn is_typeable(word: &str) -> bool {
+    word.chars().all(|ch| ch_to_key(ch).is_some())
+}
+
+/// Maps a character to the key that types it, and whether shift must be held.
+fn ch_to_key(ch: char) -> Option<(KeyCode, bool)> {
+    if ch.is_ascii_uppercase() {
+        return ch_to_keycode(ch.to_ascii_lowercase()).map(|key_code| (key_code, true));
+    }
+
+    ch_to_keycode(ch).map(|key_code| (key_code, false))
+}
+
+/// The unshifted character a key types on a QWERTY keyboard; the inverse of `ch_to_keycode`.
+pub fn keycode_to_ch(key_code: KeyCode) -> Option<char> {
+    match key_code {
+        KeyCode::Key0 => Some('0'),
+        KeyCode::Key1 => Some('1'),
+        KeyCode::Key2 => Some('2'),
+        KeyCode::Key3 => Some('3'),
+        KeyCode::Key4 => Some('4'),
+        KeyCode::Key5 => Some('5'),
+        KeyCode::Key6 => Some('6'),
+        KeyCode::Key7 => Some('7'),
+        KeyCode::Key8 => Some('8'),
+        KeyCode::Key9 => Some('9'),
+        KeyCode::A => Some('a'),
+        KeyCode::B => Some('b'),
+        KeyCode::C => Some('c'),
+        KeyCode::D => Some('d'),
+        KeyCode::E => Some('e'),
+        KeyCode::F => Some('f'),
+        KeyCode::G => Some('g'),
+        KeyCode::H => Some('h'),
+        KeyCode::I => Some('i'),
+        KeyCode::J => Some('j'),
+        KeyCode::K => Some('k'),
+        KeyCode::L => Some('l'),
+        KeyCode::M => Some('m'),
+        KeyCode::N => Some('n'),
+        KeyCode::O => Some('o'),
+        KeyCode::P => Some('p'),
+        KeyCode::Q => Some('q'),
+        KeyCode::R => Some('r'),
+        KeyCode::S => Some('s'),
+        KeyCode::T => Some('t'),
+        KeyCode::U => Some('u'),
+        KeyCode::V => Some('v'),
+        KeyCode::W => Some('w'),
+        KeyCode::X => Some('x'),
+        KeyCode::Y => Some('y'),
+        KeyCode::Z => Some('z'),
+        KeyCode::Space => Some(' '),
+        KeyCode::Comma => Some(','),
+        KeyCode::Period => Some('.'),
+        KeyCode::Apostrophe => Some('\''),
+        KeyCode::Minus => Some('-'),
+        KeyCode::Semicolon => Some(';'),
+        _ => None
+    }
+}
+
+/// The numeric keypad key that types the digit `ch`, whatever the layout.
+pub fn numpad_keycode(ch: char) -> Option<KeyCode> {
+    match ch {
+        '0' => Some(KeyCode::Kp0),
+        '1' => Some(KeyCode::Kp1),
+        '2' => Some(KeyCode::Kp2),
+        '3' => Some(KeyCode::Kp3),
+        '4' => Some(KeyCode::Kp4),
+        '5' => Some(KeyCode::Kp5),
+        '6' => Some(KeyCode::Kp6),
+        '7' => Some(KeyCode::Kp7),
+        '8' => Some(KeyCode::Kp8),
+        '9' => Some(KeyCode::Kp9),
+        _ => None
+    }
+}
+
+/// The key that types `ch` on a QWERTY keyboard.
+pub fn ch_to_keycode(ch: char) -> Option<KeyCode> {
     match ch {
         '0' => Some(KeyCode::Key0),
         '1' => Some(KeyCode::Key1),
@@ -174,8 +718,452 @@ fn ch_to_keycode(ch: char) -> Option<KeyCode> {
         'x' => Some(KeyCode::X),
         'y' => Some(KeyCode::Y),
         'z' => Some(KeyCode::Z),
+        ' ' => Some(KeyCode::Space),
+        ',' => Some(KeyCode::Comma),
+        '.' => Some(KeyCode::Period),
+        '\'' => Some(KeyCode::Apostrophe),
+        '-' => Some(KeyCode::Minus),
+        ';' => Some(KeyCode::Semicolon),
         _ => None
     }
 }
 
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_ch_to_key() {
+        assert_eq!(ch_to_key('a'), Some((KeyCode::A, false)));
+        assert_eq!(ch_to_key('A'), Some((KeyCode::A, true)));
+        assert_eq!(ch_to_key('Z'), Some((KeyCode::Z, true)));
+        assert_eq!(ch_to_key('7'), Some((KeyCode::Key7, false)));
+        assert_eq!(ch_to_key('!'), None);
+    }
+
+    #[test]
+    fn test_ch_to_keycode_punctuation() {
+        assert_eq!(ch_to_keycode(' '), Some(KeyCode::Space));
+        assert_eq!(ch_to_keycode(','), Some(KeyCode::Comma));
+        assert_eq!(ch_to_keycode('.'), Some(KeyCode::Period));
+        assert_eq!(ch_to_keycode('\''), Some(KeyCode::Apostrophe));
+        assert_eq!(ch_to_keycode('-'), Some(KeyCode::Minus));
+        assert_eq!(ch_to_keycode(';'), Some(KeyCode::Semicolon));
+    }
+
+    #[test]
+    fn test_numpad_digits() {
+        let keymap = Keymap::qwerty();
+        let mut word = Word::new("180", Point2::new(0.0, 0.0), Vector2::new(0.0, 0.0));
+
+        assert_eq!(word.type_key(KeyCode::Kp1, false, true, &keymap, 0.0), WordEvent::CharTyped);
+        assert_eq!(word.type_key(KeyCode::Key8, false, true, &keymap, 0.0), WordEvent::CharTyped);
+        assert_eq!(word.type_key(KeyCode::Kp1, false, true, &keymap, 0.0), WordEvent::None);
+        assert_eq!(word.type_key(KeyCode::Kp0, false, true, &keymap, 0.0), WordEvent::CharTyped);
+        assert_eq!(word.num_typed, 3);
+    }
+
+    #[test]
+    fn test_keycode_to_ch_round_trip() {
+        for ch in (' '..='~').filter(|ch| ch_to_keycode(*ch).is_some()) {
+            assert_eq!(keycode_to_ch(ch_to_keycode(ch).unwrap()), Some(ch));
+        }
+
+        assert_eq!(keycode_to_ch(KeyCode::LeftShift), None);
+        assert_eq!(keycode_to_ch(KeyCode::Backspace), None);
+    }
+
+    #[test]
+    fn test_type_key_shift() {
+        let keymap = Keymap::qwerty();
+        let mut word = Word::new("Ab", Point2::new(0.0, 0.0), Vector2::new(0.0, 0.0));
+
+        word.type_key(KeyCode::A, false, true, &keymap, 0.0);
+        assert_eq!(word.num_typed, 0);
+
+        word.type_key(KeyCode::A, true, true, &keymap, 0.0);
+        assert_eq!(word.num_typed, 1);
+
+        word.type_key(KeyCode::B, true, true, &keymap, 0.0);
+        assert_eq!(word.num_typed, 1);
+
+        word.type_key(KeyCode::B, false, true, &keymap, 0.0);
+        assert_eq!(word.num_typed, 2);
+    }
+
+    #[test]
+    fn test_type_key_case_insensitive() {
+        let keymap = Keymap::qwerty();
+
+        let mut word = Word::new("Ab", Point2::new(0.0, 0.0), Vector2::new(0.0, 0.0));
+        word.type_key(KeyCode::A, false, false, &keymap, 0.0);
+        assert_eq!(word.num_typed, 1);
+        word.type_key(KeyCode::B, true, false, &keymap, 0.0);
+        assert_eq!(word.num_typed, 2);
+
+        let mut word = Word::new("Ab", Point2::new(0.0, 0.0), Vector2::new(0.0, 0.0));
+        word.type_key(KeyCode::A, false, true, &keymap, 0.0);
+        assert_eq!(word.num_typed, 0);
+        word.type_key(KeyCode::A, true, true, &keymap, 0.0);
+        assert_eq!(word.num_typed, 1);
+    }
+
+    #[test]
+    fn test_completion_time() {
+        let keymap = Keymap::qwerty();
+        let mut word = Word::new("cat", Point2::new(0.0, 0.0), Vector2::new(0.0, 0.0));
+
+        word.type_key(KeyCode::X, false, true, &keymap, 1.0);
+        word.type_key(KeyCode::C, false, true, &keymap, 2.0);
+        word.type_key(KeyCode::A, false, true, &keymap, 2.5);
+        assert_eq!(word.completion_time(), None);
+
+        word.type_key(KeyCode::T, false, true, &keymap, 3.25);
+        assert_eq!(word.completion_time(), Some(1.25));
+    }
+
+    #[test]
+    fn test_type_key_backspace() {
+        let keymap = Keymap::qwerty();
+        let mut word = Word::new("cat", Point2::new(0.0, 0.0), Vector2::new(0.0, 0.0));
+
+        word.type_key(KeyCode::C, false, true, &keymap, 0.0);
+        word.type_key(KeyCode::A, false, true, &keymap, 0.0);
+        assert_eq!(word.num_typed, 2);
+
+        word.type_key(KeyCode::Backspace, false, true, &keymap, 0.0);
+        assert_eq!(word.num_typed, 1);
+
+        word.type_key(KeyCode::Backspace, false, true, &keymap, 0.0);
+        word.type_key(KeyCode::Backspace, false, true, &keymap, 0.0);
+        assert_eq!(word.num_typed, 0);
+    }
+
+    #[test]
+    fn test_backspace_after_completion() {
+        let keymap = Keymap::qwerty();
+        let mut word = Word::new("at", Point2::new(0.0, 0.0), Vector2::new(1.0, 0.0));
+
+        word.type_key(KeyCode::A, false, true, &keymap, 0.0);
+        word.type_key(KeyCode::T, false, true, &keymap, 0.0);
+        word.type_key(KeyCode::Backspace, false, true, &keymap, 0.0);
+        assert_eq!(word.num_typed, 2, "a finished word can't be un-typed");
+
+        assert_eq!(word.tick(0.016, 1.0), WordEvent::Completed);
+        word.type_key(KeyCode::Backspace, false, true, &keymap, 0.0);
+        assert_eq!(word.num_typed, 2);
+
+        let position = word.position();
+        word.tick(0.016, 1.0);
+        assert_eq!(word.position(), position, "a dying word stays put");
+    }
+
+    #[test]
+    fn test_death_palettes_differ() {
+        let color_at = |palette: DeathPalette, time: f64| {
+            let mut word = Word::new("cat", Point2::new(0.0, 0.0), Vector2::new(0.0, 0.0)).with_death_palette(palette);
+            word.death_animation.advance_to(time);
+
+            Color::from(word.death_animation.now_strict().expect("time is within the animation"))
+        };
+
+        assert_ne!(color_at(DeathPalette::Default, 0.3), color_at(DeathPalette::Rainbow, 0.3));
+        assert_ne!(color_at(DeathPalette::Default, 0.3), color_at(DeathPalette::Greens, 0.3));
+    }
+
+    #[test]
+    fn test_set_theme_keeps_animation_progress() {
+        let keymap = Keymap::qwerty();
+        let mut word = Word::new("a", Point2::new(0.0, 0.0), Vector2::new(0.0, 0.0));
+
+        word.type_key(KeyCode::A, false, true, &keymap, 0.0);
+        word.tick(0.0, 1.0);
+        word.tick(0.3, 1.0);
+        let time = word.death_animation.time();
+
+        word.set_theme(Theme::HighContrast);
+        assert_eq!(word.death_animation.time(), time);
+
+        let mut expected = Word::new("a", Point2::new(0.0, 0.0), Vector2::new(0.0, 0.0)).with_theme(Theme::HighContrast);
+        expected.death_animation.advance_to(time);
+        expected.state = WordState::Typed;
+        assert_eq!(word.typed_color(false), expected.typed_color(false));
+    }
+
+    #[test]
+    fn test_death_scale() {
+        let keymap = Keymap::qwerty();
+        let mut word = Word::new("at", Point2::new(0.0, 0.0), Vector2::new(0.0, 0.0));
+        assert_eq!(word.scale_factor(), 1.0);
+
+        word.type_key(KeyCode::A, false, true, &keymap, 0.0);
+        word.type_key(KeyCode::T, false, true, &keymap, 0.0);
+        assert_eq!(word.tick(0.0, 1.0), WordEvent::Completed);
+        assert_eq!(word.scale_factor(), 1.0);
+
+        let mut peak: f32 = 0.0;
+        while word.state == WordState::Typed {
+            word.tick(0.016, 1.0);
+            peak = peak.max(word.scale_factor());
+
+            if word.death_scale.finished() {
+                assert!(word.scale_factor() < 0.01);
+            }
+        }
+
+        assert!(peak > 1.0);
+        assert_eq!(word.scale_factor(), 0.0);
+    }
+
+    #[test]
+    fn test_death_animation_only_advances_with_game_time() {
+        let keymap = Keymap::qwerty();
+        let mut word = Word::new("a", Point2::new(0.0, 0.0), Vector2::new(0.0, 0.0));
+
+        word.type_key(KeyCode::A, false, true, &keymap, 0.0);
+        word.tick(0.0, 1.0);
+        assert_eq!(word.state, WordState::Typed);
+
+        word.tick(0.5, 1.0);
+        let time = word.death_animation.time();
+
+        // while paused no game time passes, so the animation holds where it was
+        for _ in 0..100 {
+            word.tick(0.0, 1.0);
+        }
+
+        assert_eq!(word.death_animation.time(), time);
+        assert_eq!(word.state, WordState::Typed);
+
+        word.tick(1.0, 1.0);
+        word.tick(0.0, 1.0);
+        assert_eq!(word.state, WordState::Dead);
+    }
+
+    #[test]
+    fn test_decorative_word_stays_active() {
+        let keymap = Keymap::qwerty();
+        let mut word = Word::new("15", Point2::new(0.0, 0.0), Vector2::new(0.0, 0.0)).decorative();
 
+        word.type_key(KeyCode::Key1, false, true, &keymap, 0.0);
+        word.type_key(KeyCode::Key5, false, true, &keymap, 0.0);
+        word.tick(0.016, 1.0);
+
+        assert_eq!(word.num_typed, 0);
+        assert_eq!(word.state, WordState::Active);
+
+        word.num_typed = word.len();
+        word.tick(0.016, 1.0);
+
+        assert_eq!(word.state, WordState::Active);
+    }
+
+    #[test]
+    fn test_next_char_color() {
+        let keymap = Keymap::qwerty();
+        let mut word = spawned(Word::new("cat", Point2::new(0.0, 0.0), Vector2::new(0.0, 0.0)));
+        assert_color_close(word.next_char_color(false), ColorPalette::Fg.color_for(Theme::Default));
+
+        word.type_key(KeyCode::C, false, true, &keymap, 0.0);
+        assert_eq!(word.next_char_color(false), ColorPalette::BrightYellow.color_for(Theme::Default));
+
+        word.type_key(KeyCode::X, false, true, &keymap, 0.0);
+        assert_color_close(word.next_char_color(false), ColorPalette::Fg.color_for(Theme::Default));
+
+        word.type_key(KeyCode::A, false, true, &keymap, 0.0);
+        word.type_key(KeyCode::Backspace, false, true, &keymap, 0.0);
+        assert_color_close(word.next_char_color(false), ColorPalette::Fg.color_for(Theme::Default));
+    }
+
+    #[test]
+    fn test_high_visibility_colors() {
+        let keymap = Keymap::qwerty();
+        let target = Point2::new(0.0, 100.0);
+        let mut word = spawned(Word::new("cat", Point2::new(0.0, 0.0), Vector2::new(0.0, 100.0)).with_target(target));
+        word.tick(1.0, 1.0);
+        word.type_key(KeyCode::C, false, true, &keymap, 0.0);
+
+        assert_eq!(word.typed_color(false), ColorPalette::Bg4.color_for(Theme::Default));
+        assert_color_close(word.untyped_color(false), ColorPalette::Red.color_for(Theme::Default));
+
+        assert_eq!(word.typed_color(true), ColorPalette::BrightYellow.color_for(Theme::Default));
+        assert_eq!(word.untyped_color(true), ColorPalette::Fg.color_for(Theme::Default));
+    }
+
+    #[test]
+    fn test_text_position() {
+        let position = |x, y| text_position(Point2::new(x, y), 100.0, 20.0, 800.0, 600.0);
+
+        assert_eq!(position(400.0, 300.0), Point2::new(350.0, 290.0));
+        assert_eq!(position(10.0, 5.0), Point2::new(0.0, 0.0));
+        assert_eq!(position(790.0, 595.0), Point2::new(700.0, 580.0));
+
+        // still on its way in
+        assert_eq!(position(-30.0, 300.0), Point2::new(-80.0, 290.0));
+        assert_eq!(position(400.0, 650.0), Point2::new(350.0, 640.0));
+
+        // wider than the screen
+        assert_eq!(text_position(Point2::new(400.0, 300.0), 1000.0, 20.0, 800.0, 600.0).x, 0.0);
+    }
+
+    /// Skips past the fade-in, so tests see the word's settled colors.
+    fn spawned(mut word: Word) -> Word {
+        word.spawn_animation.advance_to(SPAWN_ANIMATION_SECS);
+
+        word
+    }
+
+    fn assert_color_close(left: Color, right: Color) {
+        let close = |a: f32, b: f32| (a - b).abs() < 0.01;
+
+        assert!(
+            close(left.r, right.r) && close(left.g, right.g) && close(left.b, right.b) && close(left.a, right.a),
+            "{left:?} != {right:?}",
+        );
+    }
+
+    #[test]
+    fn test_spawn_animation() {
+        let mut word = Word::new("cat", Point2::new(0.0, 0.0), Vector2::new(0.0, 0.0));
+        assert!(!word.spawn_animation.finished());
+        assert_color_close(word.untyped_color(false), ColorPalette::Bg.color_for(Theme::Default));
+
+        word.tick(SPAWN_ANIMATION_SECS / 2.0, 1.0);
+        assert!(!word.spawn_animation.finished());
+
+        word.tick(SPAWN_ANIMATION_SECS, 1.0);
+        assert!(word.spawn_animation.finished());
+        assert_color_close(word.untyped_color(false), ColorPalette::Fg.color_for(Theme::Default));
+    }
+
+    #[test]
+    fn test_danger_color() {
+        let target = Point2::new(0.0, 100.0);
+
+        let far = spawned(Word::new("cat", Point2::new(0.0, 0.0), Vector2::new(0.0, 0.0)).with_target(target));
+        assert_eq!(far.danger(), 0.0);
+        assert_color_close(far.untyped_color(false), ColorPalette::Fg.color_for(Theme::Default));
+
+        let mut near = spawned(Word::new("cat", Point2::new(0.0, 0.0), Vector2::new(0.0, 100.0)).with_target(target));
+        near.tick(1.0, 1.0);
+        assert_eq!(near.danger(), 1.0);
+        assert_color_close(near.untyped_color(false), ColorPalette::Red.color_for(Theme::Default));
+
+        let mut halfway = spawned(Word::new("cat", Point2::new(0.0, 0.0), Vector2::new(0.0, 50.0)).with_target(target));
+        halfway.tick(1.0, 1.0);
+        assert_eq!(halfway.danger(), 0.5);
+
+        // small steps toward the target don't change the color
+        let mut creeping = spawned(Word::new("cat", Point2::new(0.0, 0.0), Vector2::new(0.0, 1.0)).with_target(target));
+        creeping.tick(1.0, 1.0);
+        assert_eq!(creeping.danger(), 0.0);
+        creeping.tick(2.0, 1.0);
+        assert_eq!(creeping.danger(), 0.0);
+        creeping.tick(3.0, 1.0);
+        assert_eq!(creeping.danger(), 0.1);
+    }
+
+    #[test]
+    fn test_movement_is_frame_rate_independent() {
+        let displacement = |fps: u32| {
+            let mut word = Word::new("cat", Point2::new(0.0, 0.0), Vector2::new(90.0, -30.0));
+
+            for _ in 0..fps {
+                word.tick(1.0 / fps as f64, 1.0);
+            }
+
+            word.position()
+        };
+
+        for fps in [30, 60, 144] {
+            let position = displacement(fps);
+
+            assert!((position.x - 90.0).abs() < 0.01, "{fps} fps: {position:?}");
+            assert!((position.y + 30.0).abs() < 0.01, "{fps} fps: {position:?}");
+        }
+    }
+
+    #[test]
+    fn test_type_key_mismatch() {
+        let keymap = Keymap::qwerty();
+        let mut word = Word::new("cat", Point2::new(0.0, 0.0), Vector2::new(0.0, 0.0));
+
+        word.type_key(KeyCode::C, false, true, &keymap, 0.0);
+        word.type_key(KeyCode::X, false, true, &keymap, 0.0);
+        assert_eq!(word.num_typed, 1);
+
+        let mut word = Word::new("cat", Point2::new(0.0, 0.0), Vector2::new(0.0, 0.0))
+            .with_strict(true);
+
+        word.type_key(KeyCode::C, false, true, &keymap, 0.0);
+        word.type_key(KeyCode::LeftShift, true, true, &keymap, 0.0);
+        assert_eq!(word.num_typed, 1);
+
+        word.type_key(KeyCode::X, false, true, &keymap, 0.0);
+        assert_eq!(word.num_typed, 0);
+    }
+
+    #[test]
+    fn test_unmapped_chars_are_skipped() {
+        let keymap = Keymap::qwerty();
+        let mut word = Word::new("éclair", Point2::new(0.0, 0.0), Vector2::new(0.0, 0.0));
+
+        word.type_key(KeyCode::X, false, true, &keymap, 0.0);
+        assert_eq!(word.num_typed, 0);
+
+        word.type_key(KeyCode::C, false, true, &keymap, 0.0);
+        assert_eq!(word.num_typed, 2);
+
+        let mut word = Word::new("caf€", Point2::new(0.0, 0.0), Vector2::new(0.0, 0.0));
+
+        for key in [KeyCode::C, KeyCode::A, KeyCode::F] {
+            word.type_key(key, false, true, &keymap, 0.0);
+        }
+        assert_eq!(word.num_typed, word.len());
+
+        word.tick(0.016, 1.0);
+        assert_eq!(word.state, WordState::Typed);
+    }
+
+    #[test]
+    fn test_text_rebuilds_only_when_dirty() {
+        let mut word = Word::new("cat", Point2::new(0.0, 0.0), Vector2::new(0.0, 0.0));
+        let refresh = |word: &mut Word, scale| {
+            word.text.set_key(word.text_key(Font::default(), scale, false));
+            let dirty = word.text.is_dirty();
+            word.text.get(|key| Word::build_text(&word.word, key), |_| (0.0, 0.0));
+
+            dirty
+        };
+
+        assert!(refresh(&mut word, 24.0));
+        assert!(!refresh(&mut word, 24.0), "unchanged text shouldn't be rebuilt");
+
+        word.num_typed = 1;
+        assert!(refresh(&mut word, 24.0));
+        assert!(refresh(&mut word, 48.0));
+    }
+
+    #[test]
+    fn test_word_events() {
+        let keymap = Keymap::qwerty();
+        let mut word = Word::new("at", Point2::new(0.0, 0.0), Vector2::new(0.0, 0.0));
+
+        assert_eq!(word.type_key(KeyCode::X, false, true, &keymap, 0.0), WordEvent::None);
+        assert_eq!(word.type_key(KeyCode::A, false, true, &keymap, 0.0), WordEvent::CharTyped);
+        assert_eq!(word.tick(0.016, 1.0), WordEvent::None);
+        assert_eq!(word.type_key(KeyCode::T, false, true, &keymap, 0.0), WordEvent::CharTyped);
+
+        assert_eq!(word.tick(0.016, 1.0), WordEvent::Completed);
+        assert_eq!(word.tick(0.016, 1.0), WordEvent::None);
+
+        let mut died = 0;
+        for _ in 0..200 {
+            if word.tick(0.016, 1.0) == WordEvent::Died {
+                died += 1;
+            }
+        }
+        assert_eq!(died, 1);
+        assert_eq!(word.state, WordState::Dead);
+    }
+}
@@ -0,0 +1,329 @@
+use std::io::Read;
+
+use good_web_game::{filesystem, Context};
+
+use crate::word::{is_typeable, DeathPalette};
+
+const WORDS_FILE: &str = "/words.txt";
+
+/// A themed set of words to play with.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum WordPack {
+    #[default]
+    ThreeLetter,
+    Animals,
+    Colors,
+    Programming,
+    /// Words from `words.txt` in the resources directory.
+    Custom,
+}
+
+impl WordPack {
+    /// The pack's built-in words, or `None` for packs loaded from disk.
+    pub fn builtin_words(self) -> Option<&'static [&'static str]> {
+        match self {
+            Self::ThreeLetter => Some(&WORD_LIST),
+            Self::Animals => Some(&ANIMALS),
+            Self::Colors => Some(&COLORS),
+            Self::Programming => Some(&PROGRAMMING),
+            Self::Custom => None,
+        }
+    }
+
+    /// How the pack's words flash as they die.
+    pub fn death_palette(self) -> DeathPalette {
+        match self {
+            Self::Animals => DeathPalette::Greens,
+            Self::Colors => DeathPalette::Rainbow,
+            _ => DeathPalette::Default,
+        }
+    }
+}
+
+/// The words for `pack`, falling back to the built-in list if a custom pack has no usable words.
+pub fn load_word_list(ctx: &mut Context, pack: WordPack) -> Vec<String> {
+    match pack.builtin_words() {
+        Some(words) => words.iter().map(|word| word.to_string()).collect(),
+        None => load_custom_word_list(ctx),
+    }
+}
+
+/// Reads the newline-delimited word list from the resources directory, falling back to the
+/// built-in list if the file is missing or has no usable words.
+fn load_custom_word_list(ctx: &mut Context) -> Vec<String> {
+    // Blocking loads never complete on wasm, so only the built-in list is available there.
+    if cfg!(target_arch = "wasm32") {
+        return builtin_word_list();
+    }
+
+    let mut contents = String::new();
+
+    let words = match filesystem::open(ctx, WORDS_FILE) {
+        Ok(mut file) => match file.read_to_string(&mut contents) {
+            Ok(_) => parse_word_list(&contents),
+            Err(e) => {
+                eprintln!("warning: couldn't read {WORDS_FILE}: {e}");
+                vec![]
+            },
+        },
+        Err(_) => vec![],
+    };
+
+    if words.is_empty() {
+        builtin_word_list()
+    } else {
+        words
+    }
+}
+
+pub fn builtin_word_list() -> Vec<String> {
+    WORD_LIST.iter().map(|word| word.to_string()).collect()
+}
+
+/// Splits `contents` into one word per line, skipping blank lines and words containing
+/// characters that can't be typed.
+fn parse_word_list(contents: &str) -> Vec<String> {
+    contents.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .filter(|line| {
+            let typeable = is_typeable(line);
+
+            if !typeable {
+                eprintln!("warning: skipping word with untypeable characters: {line:?}");
+            }
+
+            typeable
+        })
+        .map(String::from)
+        .collect()
+}
+
+const WORD_LIST: [&str; 171] = [
+    "and",		
+    "are",		
+    "ape",		
+    "ace",		
+    "act",		
+    "ask",		
+    "arm",		
+    "age",		
+    "ago",		
+    "air",		
+    "ate",		
+    "all",		
+    "but",		
+    "bye",		
+    "bad",		
+    "big",		
+    "bed",		
+    "bat",		
+    "boy",		
+    "bus",		
+    "bag",		
+    "box",		
+    "bit",		
+    "bee",		
+    "buy",		
+    "bun",		
+    "cub",		
+    "cat",		
+    "car",		
+    "cut",		
+    "cow",		
+    "cry",		
+    "cab",		
+    "can",		
+    "dad",		
+    "dab",		
+    "dam",		
+    "did",		
+    "dug",		
+    "den",		
+    "dot",		
+    "dip",		
+    "day",		
+    "ear",		
+    "eye",		
+    "eat",		
+    "end",		
+    "elf",		
+    "egg",		
+    "far",		
+    "fat",		
+    "few",		
+    "fan",		
+    "fun",		
+    "fit",		
+    "fin",		
+    "fox",		
+    "fix",
+    "fly",
+    "fry",
+    "for",
+    "got",
+    "get",
+    "god",
+    "gel",
+    "gas",
+    "hat",
+    "hit",
+    "has",
+    "had",
+    "how",
+    "her",
+    "his",
+    "hen",
+    "ink",
+    "ice",
+    "ill",
+    "jab",
+    "jug",
+    "jet",
+    "jam",
+    "jar",
+    "job",
+    "jog",
+    "kit",
+    "key",
+    "lot",
+    "lit",
+    "let",
+    "lay",
+    "mat",
+    "man",
+    "mad",
+    "mug",
+    "mix",
+    "map",
+    "mum",
+    "mud",
+    "mom",
+    "may",
+    "met",
+    "net",
+    "new",
+    "nap",
+    "now",
+    "nod",
+    "net",
+    "not",
+    "nut",
+    "oar",
+    "one",
+    "out",
+    "owl",
+    "old",
+    "own",
+    "odd",
+    "our",
+    "pet",
+    "pat",
+    "peg",
+    "paw",
+    "pup",
+    "pit",
+    "put",
+    "pot",
+    "pop",
+    "pin",
+    "rat",
+    "rag",
+    "rub",
+    "row",
+    "rug",
+    "run",
+    "rap",
+    "ram",
+    "sow",
+    "see",
+    "saw",
+    "set",
+    "sit",
+    "sir",
+    "sat",
+    "sob",
+    "tap",
+    "tip",
+    "top",
+    "tug",
+    "tow",
+    "toe",
+    "tan",
+    "ten",
+    "two",
+    "use",
+    "van",
+    "vet",
+    "was",
+    "wet",
+    "win",
+    "won",
+    "wig",
+    "war",
+    "why",
+    "who",
+    "way",
+    "wow",
+    "you",
+    "yes",
+    "yak",
+    "yet",
+    "zip",
+    "zap",
+];
+
+const ANIMALS: [&str; 40] = [
+    "ant", "bat", "bear", "bee", "bison", "camel", "cat", "cow", "crab", "crow",
+    "deer", "dog", "duck", "eagle", "eel", "elk", "fox", "frog", "goat", "goose",
+    "hare", "horse", "koala", "lion", "llama", "mole", "moose", "mouse", "otter", "owl",
+    "panda", "pig", "puma", "rat", "seal", "shark", "sheep", "tiger", "wolf", "zebra",
+];
+
+const COLORS: [&str; 30] = [
+    "amber", "aqua", "azure", "beige", "black", "blue", "brown", "coral", "cream", "cyan",
+    "gold", "gray", "green", "indigo", "ivory", "khaki", "lemon", "lilac", "lime", "maroon",
+    "mauve", "navy", "olive", "orange", "peach", "pink", "plum", "red", "teal", "white",
+];
+
+const PROGRAMMING: [&str; 40] = [
+    "array", "async", "await", "borrow", "break", "bug", "byte", "cargo", "class", "const",
+    "crate", "debug", "enum", "float", "fn", "heap", "impl", "index", "loop", "macro",
+    "match", "merge", "mut", "null", "parse", "queue", "ref", "regex", "return", "rust",
+    "stack", "string", "struct", "trait", "tuple", "type", "unsafe", "vector", "while", "yield",
+];
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const ALL_PACKS: [WordPack; 5] = [
+        WordPack::ThreeLetter,
+        WordPack::Animals,
+        WordPack::Colors,
+        WordPack::Programming,
+        WordPack::Custom,
+    ];
+
+    #[test]
+    fn test_word_packs_are_typeable() {
+        for pack in ALL_PACKS {
+            for word in pack.builtin_words().unwrap_or_default() {
+                assert!(is_typeable(word), "{pack:?} has untypeable word {word:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_word_list() {
+        let contents = "cat\n\n  dog  \nHello\nna\u{ef}ve\n\t\nthe end\n";
+
+        assert_eq!(parse_word_list(contents), vec!["cat", "dog", "Hello", "the end"]);
+    }
+
+    #[test]
+    fn test_parse_word_list_empty() {
+        assert!(parse_word_list("").is_empty());
+        assert!(parse_word_list("\n  \n").is_empty());
+    }
+}
@@ -0,0 +1,90 @@
+use animated_memory::{
+    game::{Difficulty, Game, SpawnStrategy},
+    word::{Word, WordState},
+};
+use good_web_game::input::keyboard::KeyCode;
+
+const DT: f32 = 0.016;
+
+/// A game over `words` that has finished its countdown and spawned its first word.
+fn started_game(words: &[&str]) -> Game {
+    let word_list: Vec<String> = words.iter().map(|word| word.to_string()).collect();
+    let mut game = Game::new_seeded(800.0, 600.0, Difficulty::Normal, SpawnStrategy::Ring, &word_list, 1);
+
+    game.step(&[], 3.0);
+    game.step(&[], Difficulty::Normal.spawn_interval() as f32);
+
+    game
+}
+
+fn playable(game: &Game) -> Vec<&Word> {
+    game.words().iter().filter(|word| !word.is_decorative()).collect()
+}
+
+#[test]
+fn test_typing_a_word_scores() {
+    let mut game = started_game(&["cat"]);
+
+    assert_eq!(playable(&game).len(), 1);
+    assert_eq!(game.score(), 0);
+
+    for key in [KeyCode::C, KeyCode::A, KeyCode::T] {
+        game.step(&[(key, false)], DT);
+    }
+
+    assert_eq!(playable(&game)[0].state, WordState::Typed);
+    assert_eq!(game.score(), 30);
+}
+
+#[test]
+fn test_summary() {
+    let mut game = started_game(&["cat"]);
+
+    game.step(&[(KeyCode::X, false)], DT);
+    for key in [KeyCode::C, KeyCode::A, KeyCode::T] {
+        game.step(&[(key, false)], DT);
+    }
+    for _ in 0..60 {
+        game.step(&[], DT);
+    }
+
+    let summary = game.summary();
+    assert_eq!(summary.words_completed, 1);
+    assert_eq!(summary.score, game.score());
+    assert_eq!(summary.level, game.level());
+    assert_eq!(summary.wpm, game.stats().wpm());
+    assert_eq!(summary.accuracy, game.stats().accuracy());
+    assert_eq!(summary.duration, game.elapsed_secs());
+}
+
+#[test]
+fn test_wrong_keys_do_nothing() {
+    let mut game = started_game(&["cat"]);
+
+    game.step(&[(KeyCode::X, false), (KeyCode::Y, false)], DT);
+    game.step(&[(KeyCode::C, false), (KeyCode::Z, false)], DT);
+
+    let words = playable(&game);
+    assert_eq!(words[0].state, WordState::Active);
+    assert_eq!(words[0].num_typed, 1);
+    assert_eq!(game.score(), 0);
+}
+
+#[test]
+fn test_shift_for_capitals() {
+    let mut game = started_game(&["Go"]).with_case_sensitive(true);
+
+    game.step(&[(KeyCode::G, false)], DT);
+    assert_eq!(playable(&game)[0].num_typed, 0);
+
+    game.step(&[(KeyCode::G, true), (KeyCode::O, false)], DT);
+    assert_eq!(playable(&game)[0].state, WordState::Typed);
+}
+
+#[test]
+fn test_any_case_by_default() {
+    let mut game = started_game(&["Go"]);
+
+    game.step(&[(KeyCode::G, false), (KeyCode::O, false)], DT);
+    assert_eq!(playable(&game)[0].state, WordState::Typed);
+}
@@ -0,0 +1,26 @@
+use animated_memory::menu::{Menu, MenuAction, EXIT, NEW_GAME, SETTINGS};
+
+#[test]
+fn test_menu_navigation() {
+    let mut menu = Menu::new("Test", &[
+        (NEW_GAME, MenuAction::NewGame),
+        (SETTINGS, MenuAction::Settings),
+        (EXIT, MenuAction::Exit),
+    ]);
+
+    assert_eq!(menu.selected_action(), Some(MenuAction::NewGame));
+
+    menu.next_selection();
+    menu.next_selection();
+    assert_eq!(menu.selected_item(), Some(EXIT));
+
+    // wraps around in both directions
+    menu.next_selection();
+    assert_eq!(menu.selected_action(), Some(MenuAction::NewGame));
+
+    menu.prev_selection();
+    assert_eq!(menu.selected_action(), Some(MenuAction::Exit));
+
+    menu.select_action(MenuAction::Settings);
+    assert_eq!(menu.selected_item(), Some(SETTINGS));
+}